@@ -0,0 +1,339 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::direction::Direction;
+use crate::orientation::Orientation;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Combobox,
+    Dialog,
+    Group,
+    Listbox,
+    Option,
+    Presentation,
+    Slider,
+    Tab,
+    TabList,
+    TabPanel,
+}
+
+impl Role {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Role::Combobox => "combobox",
+            Role::Dialog => "dialog",
+            Role::Group => "group",
+            Role::Listbox => "listbox",
+            Role::Option => "option",
+            Role::Presentation => "presentation",
+            Role::Slider => "slider",
+            Role::Tab => "tab",
+            Role::TabList => "tablist",
+            Role::TabPanel => "tabpanel",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataState {
+    Open,
+    Closed,
+    Active,
+    Inactive,
+}
+
+impl DataState {
+    pub fn open(open: bool) -> Self {
+        if open { DataState::Open } else { DataState::Closed }
+    }
+
+    pub fn active(active: bool) -> Self {
+        if active {
+            DataState::Active
+        } else {
+            DataState::Inactive
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DataState::Open => "open",
+            DataState::Closed => "closed",
+            DataState::Active => "active",
+            DataState::Inactive => "inactive",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HasPopup {
+    True,
+    Dialog,
+    Listbox,
+    Menu,
+}
+
+impl HasPopup {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            HasPopup::True => "true",
+            HasPopup::Dialog => "dialog",
+            HasPopup::Listbox => "listbox",
+            HasPopup::Menu => "menu",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoComplete {
+    None,
+    Inline,
+    List,
+    Both,
+}
+
+impl AutoComplete {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AutoComplete::None => "none",
+            AutoComplete::Inline => "inline",
+            AutoComplete::List => "list",
+            AutoComplete::Both => "both",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttrValue {
+    String(String),
+    Bool(bool),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Attr {
+    Role(Role),
+    Id(String),
+    TabIndex(i32),
+    Hidden(bool),
+    Disabled(bool),
+    Dir(Direction),
+    Style(String),
+    AutoCompleteOff,
+    SpellCheck(bool),
+    AriaActiveDescendant(String),
+    AriaAutoComplete(AutoComplete),
+    AriaControls(String),
+    AriaDescribedBy(String),
+    AriaDisabled(bool),
+    AriaExpanded(bool),
+    AriaHasPopup(HasPopup),
+    AriaHidden(bool),
+    AriaLabel(String),
+    AriaLabelledBy(String),
+    AriaModal(bool),
+    AriaOrientation(Orientation),
+    AriaRoleDescription(String),
+    AriaSelected(bool),
+    AriaValueMax(f64),
+    AriaValueMin(f64),
+    AriaValueNow(f64),
+    AriaValueText(String),
+    DataState(DataState),
+    DataSheetState(DataState),
+    DataColor(String),
+    DataSnapPoint(usize),
+    DataDisabled(bool),
+    DataDragging(bool),
+    DataHighlighted(bool),
+}
+
+impl Attr {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Attr::Role(_) => "role",
+            Attr::Id(_) => "id",
+            Attr::TabIndex(_) => "tabindex",
+            Attr::Hidden(_) => "hidden",
+            Attr::Disabled(_) => "disabled",
+            Attr::Dir(_) => "dir",
+            Attr::Style(_) => "style",
+            Attr::AutoCompleteOff => "autocomplete",
+            Attr::SpellCheck(_) => "spellcheck",
+            Attr::AriaActiveDescendant(_) => "aria-activedescendant",
+            Attr::AriaAutoComplete(_) => "aria-autocomplete",
+            Attr::AriaControls(_) => "aria-controls",
+            Attr::AriaDescribedBy(_) => "aria-describedby",
+            Attr::AriaDisabled(_) => "aria-disabled",
+            Attr::AriaExpanded(_) => "aria-expanded",
+            Attr::AriaHasPopup(_) => "aria-haspopup",
+            Attr::AriaHidden(_) => "aria-hidden",
+            Attr::AriaLabel(_) => "aria-label",
+            Attr::AriaLabelledBy(_) => "aria-labelledby",
+            Attr::AriaModal(_) => "aria-modal",
+            Attr::AriaOrientation(_) => "aria-orientation",
+            Attr::AriaRoleDescription(_) => "aria-roledescription",
+            Attr::AriaSelected(_) => "aria-selected",
+            Attr::AriaValueMax(_) => "aria-valuemax",
+            Attr::AriaValueMin(_) => "aria-valuemin",
+            Attr::AriaValueNow(_) => "aria-valuenow",
+            Attr::AriaValueText(_) => "aria-valuetext",
+            Attr::DataState(_) => "data-state",
+            Attr::DataSheetState(_) => "data-sheet-state",
+            Attr::DataColor(_) => "data-color",
+            Attr::DataSnapPoint(_) => "data-snap-point",
+            Attr::DataDisabled(_) => "data-disabled",
+            Attr::DataDragging(_) => "data-dragging",
+            Attr::DataHighlighted(_) => "data-highlighted",
+        }
+    }
+
+    pub fn value(&self) -> AttrValue {
+        let text = match self {
+            Attr::Hidden(value)
+            | Attr::Disabled(value)
+            | Attr::DataDisabled(value)
+            | Attr::DataDragging(value)
+            | Attr::DataHighlighted(value) => return AttrValue::Bool(*value),
+            Attr::Role(role) => role.as_str().to_string(),
+            Attr::Id(value)
+            | Attr::Style(value)
+            | Attr::AriaActiveDescendant(value)
+            | Attr::AriaControls(value)
+            | Attr::AriaDescribedBy(value)
+            | Attr::AriaLabel(value)
+            | Attr::AriaLabelledBy(value)
+            | Attr::AriaRoleDescription(value)
+            | Attr::AriaValueText(value)
+            | Attr::DataColor(value) => value.clone(),
+            Attr::TabIndex(index) => index.to_string(),
+            Attr::Dir(direction) => direction.as_attr_value().to_string(),
+            Attr::AutoCompleteOff => "off".to_string(),
+            Attr::SpellCheck(value)
+            | Attr::AriaDisabled(value)
+            | Attr::AriaExpanded(value)
+            | Attr::AriaHidden(value)
+            | Attr::AriaModal(value)
+            | Attr::AriaSelected(value) => aria_bool(*value).to_string(),
+            Attr::AriaAutoComplete(value) => value.as_str().to_string(),
+            Attr::AriaHasPopup(value) => value.as_str().to_string(),
+            Attr::AriaOrientation(orientation) => orientation.as_aria_value().to_string(),
+            Attr::AriaValueMax(value) | Attr::AriaValueMin(value) | Attr::AriaValueNow(value) => {
+                value.to_string()
+            }
+            Attr::DataState(state) | Attr::DataSheetState(state) => state.as_str().to_string(),
+            Attr::DataSnapPoint(index) => index.to_string(),
+        };
+        AttrValue::String(text)
+    }
+}
+
+fn aria_bool(value: bool) -> &'static str {
+    if value { "true" } else { "false" }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AttrSet {
+    attrs: Vec<Attr>,
+}
+
+impl AttrSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, attr: Attr) -> Self {
+        self.set(attr);
+        self
+    }
+
+    pub fn with_if(self, condition: bool, attr: impl FnOnce() -> Attr) -> Self {
+        if condition { self.with(attr()) } else { self }
+    }
+
+    pub fn with_opt<T>(self, value: Option<T>, attr: impl FnOnce(T) -> Attr) -> Self {
+        match value {
+            Some(value) => self.with(attr(value)),
+            None => self,
+        }
+    }
+
+    pub fn set(&mut self, attr: Attr) {
+        match self
+            .attrs
+            .iter_mut()
+            .find(|existing| existing.name() == attr.name())
+        {
+            Some(existing) => *existing = attr,
+            None => self.attrs.push(attr),
+        }
+    }
+
+    pub fn extend(mut self, other: AttrSet) -> Self {
+        for attr in other.attrs {
+            self.set(attr);
+        }
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Attr> {
+        self.attrs.iter().find(|attr| attr.name() == name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.attrs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.attrs.is_empty()
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, Attr> {
+        self.attrs.iter()
+    }
+}
+
+impl IntoIterator for AttrSet {
+    type Item = Attr;
+    type IntoIter = alloc::vec::IntoIter<Attr>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.attrs.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a AttrSet {
+    type Item = &'a Attr;
+    type IntoIter = core::slice::Iter<'a, Attr>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.attrs.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Attr, AttrSet, AttrValue, DataState, Role};
+
+    #[test]
+    fn attr_set_replaces_by_name_and_renders_values() {
+        let attrs = AttrSet::new()
+            .with(Attr::Role(Role::Tab))
+            .with(Attr::TabIndex(0))
+            .with(Attr::AriaSelected(true))
+            .with_if(false, || Attr::DataDisabled(true))
+            .with_opt(None::<&str>, |id| Attr::Id(id.into()))
+            .with(Attr::TabIndex(-1));
+        assert_eq!(attrs.len(), 3);
+        assert_eq!(
+            attrs.get("tabindex").map(Attr::value),
+            Some(AttrValue::String("-1".into()))
+        );
+        assert_eq!(
+            Attr::AriaSelected(true).value(),
+            AttrValue::String("true".into())
+        );
+        assert_eq!(Attr::Hidden(false).value(), AttrValue::Bool(false));
+        assert_eq!(Attr::DataState(DataState::active(false)).value(), AttrValue::String("inactive".into()));
+    }
+}
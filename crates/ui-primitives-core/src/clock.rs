@@ -0,0 +1,174 @@
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt;
+
+pub type TimerId = u64;
+pub type TimerTask = Box<dyn FnOnce()>;
+
+pub trait Clock {
+    fn now(&self) -> u64;
+}
+
+pub trait Scheduler: Clock {
+    fn schedule(&self, delay_ms: u64, task: TimerTask) -> TimerId;
+    fn cancel(&self, id: TimerId) -> bool;
+}
+
+struct ManualTimer {
+    id: TimerId,
+    deadline: u64,
+    task: TimerTask,
+}
+
+#[derive(Default)]
+struct ManualClockState {
+    now: u64,
+    next_id: TimerId,
+    pending: Vec<ManualTimer>,
+}
+
+#[derive(Clone, Default)]
+pub struct ManualClock {
+    state: Rc<RefCell<ManualClockState>>,
+}
+
+impl ManualClock {
+    pub fn new(now: u64) -> Self {
+        let clock = Self::default();
+        clock.state.borrow_mut().now = now;
+        clock
+    }
+
+    pub fn pending(&self) -> usize {
+        self.state.borrow().pending.len()
+    }
+
+    pub fn next_deadline(&self) -> Option<u64> {
+        self.state
+            .borrow()
+            .pending
+            .iter()
+            .map(|timer| timer.deadline)
+            .min()
+    }
+
+    pub fn advance(&self, delta_ms: u64) -> usize {
+        let target = self.now().saturating_add(delta_ms);
+        self.advance_to(target)
+    }
+
+    pub fn advance_to(&self, target: u64) -> usize {
+        let mut fired = 0;
+        loop {
+            let task = {
+                let mut state = self.state.borrow_mut();
+                let due = state
+                    .pending
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, timer)| timer.deadline <= target)
+                    .min_by_key(|(_, timer)| (timer.deadline, timer.id))
+                    .map(|(index, _)| index);
+                match due {
+                    Some(index) => {
+                        let timer = state.pending.remove(index);
+                        state.now = state.now.max(timer.deadline);
+                        timer.task
+                    }
+                    None => {
+                        state.now = state.now.max(target);
+                        break;
+                    }
+                }
+            };
+            task();
+            fired += 1;
+        }
+        fired
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> u64 {
+        self.state.borrow().now
+    }
+}
+
+impl Scheduler for ManualClock {
+    fn schedule(&self, delay_ms: u64, task: TimerTask) -> TimerId {
+        let mut state = self.state.borrow_mut();
+        let id = state.next_id;
+        state.next_id += 1;
+        let deadline = state.now.saturating_add(delay_ms);
+        state.pending.push(ManualTimer { id, deadline, task });
+        id
+    }
+
+    fn cancel(&self, id: TimerId) -> bool {
+        let mut state = self.state.borrow_mut();
+        let before = state.pending.len();
+        state.pending.retain(|timer| timer.id != id);
+        state.pending.len() != before
+    }
+}
+
+impl fmt::Debug for ManualClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state.borrow();
+        f.debug_struct("ManualClock")
+            .field("now", &state.now)
+            .field("pending", &state.pending.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Clock, ManualClock, Scheduler};
+    use alloc::boxed::Box;
+    use alloc::rc::Rc;
+    use core::cell::RefCell;
+    use std::vec::Vec;
+
+    #[test]
+    fn manual_clock_runs_due_tasks_in_order() {
+        let clock = ManualClock::new(100);
+        let log = Rc::new(RefCell::new(Vec::new()));
+        for (delay, label) in [(30, "b"), (10, "a"), (50, "c")] {
+            let log = Rc::clone(&log);
+            clock.schedule(delay, Box::new(move || log.borrow_mut().push(label)));
+        }
+        assert_eq!(clock.next_deadline(), Some(110));
+        assert_eq!(clock.advance(40), 2);
+        assert_eq!(clock.now(), 140);
+        assert_eq!(*log.borrow(), ["a", "b"]);
+        assert_eq!(clock.pending(), 1);
+    }
+
+    #[test]
+    fn manual_clock_cancels_and_reschedules() {
+        let clock = ManualClock::new(0);
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let cancelled = {
+            let log = Rc::clone(&log);
+            clock.schedule(10, Box::new(move || log.borrow_mut().push("cancelled")))
+        };
+        assert!(clock.cancel(cancelled));
+        assert!(!clock.cancel(cancelled));
+
+        let inner = clock.clone();
+        let chained = Rc::clone(&log);
+        clock.schedule(
+            5,
+            Box::new(move || {
+                chained.borrow_mut().push("first");
+                let chained = Rc::clone(&chained);
+                inner.schedule(5, Box::new(move || chained.borrow_mut().push("second")));
+            }),
+        );
+        assert_eq!(clock.advance(20), 2);
+        assert_eq!(*log.borrow(), ["first", "second"]);
+    }
+}
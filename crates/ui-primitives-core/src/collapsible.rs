@@ -1,9 +1,19 @@
+use crate::aria::{Attr, AttrSet, DataState};
+use crate::model::{Effect, Model, Outcome};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CollapsibleState {
     Open,
     Closed,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollapsibleEvent {
+    Toggle,
+    SetOpen(bool),
+    SetDisabled(bool),
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CollapsibleModel {
     open: bool,
@@ -56,9 +66,47 @@ impl CollapsibleModel {
     }
 }
 
+impl Model for CollapsibleModel {
+    type Event = CollapsibleEvent;
+    type Key = ();
+
+    fn send(&mut self, event: CollapsibleEvent) -> Outcome {
+        let changed = match event {
+            CollapsibleEvent::Toggle => self.toggle(),
+            CollapsibleEvent::SetOpen(open) => self.set_open(open),
+            CollapsibleEvent::SetDisabled(disabled) => {
+                self.set_disabled(disabled);
+                false
+            }
+        };
+        if changed {
+            Outcome::none().with(Effect::OpenChanged(self.open))
+        } else {
+            Outcome::none()
+        }
+    }
+}
+
+pub fn collapsible_trigger_attrs(model: &CollapsibleModel, controls: Option<&str>) -> AttrSet {
+    AttrSet::new()
+        .with(Attr::DataState(DataState::open(model.open())))
+        .with(Attr::AriaExpanded(model.open()))
+        .with(Attr::Disabled(model.disabled()))
+        .with_if(model.disabled(), || Attr::DataDisabled(true))
+        .with_opt(controls, |controls| Attr::AriaControls(controls.into()))
+}
+
+pub fn collapsible_content_attrs(model: &CollapsibleModel, content_id: Option<&str>) -> AttrSet {
+    AttrSet::new()
+        .with(Attr::DataState(DataState::open(model.open())))
+        .with(Attr::Hidden(!model.open()))
+        .with_opt(content_id, |id| Attr::Id(id.into()))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{CollapsibleModel, CollapsibleState};
+    use super::{CollapsibleEvent, CollapsibleModel, CollapsibleState};
+    use crate::model::{Effect, Model};
 
     #[test]
     fn collapsible_toggle() {
@@ -75,4 +123,16 @@ mod tests {
         assert!(!model.toggle());
         assert_eq!(model.state(), CollapsibleState::Closed);
     }
+
+    #[test]
+    fn collapsible_send_reports_open_changes() {
+        let mut model = CollapsibleModel::new(false);
+        assert_eq!(
+            model.send(CollapsibleEvent::Toggle).effects(),
+            [Effect::OpenChanged(true)]
+        );
+        assert!(model.send(CollapsibleEvent::SetOpen(true)).is_empty());
+        model.send(CollapsibleEvent::SetDisabled(true));
+        assert!(model.send(CollapsibleEvent::Toggle).is_empty());
+    }
 }
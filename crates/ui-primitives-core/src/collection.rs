@@ -0,0 +1,296 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectionItem<K> {
+    key: K,
+    text: String,
+    disabled: bool,
+    section: Option<String>,
+}
+
+impl<K> CollectionItem<K> {
+    pub fn new(key: K) -> Self {
+        Self {
+            key,
+            text: String::new(),
+            disabled: false,
+            section: None,
+        }
+    }
+
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn with_section(mut self, section: impl Into<String>) -> Self {
+        self.section = Some(section.into());
+        self
+    }
+
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn disabled(&self) -> bool {
+        self.disabled
+    }
+
+    pub fn section(&self) -> Option<&str> {
+        self.section.as_deref()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectionSection<'a, K> {
+    pub heading: Option<&'a str>,
+    pub items: Vec<&'a CollectionItem<K>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collection<K> {
+    items: Vec<CollectionItem<K>>,
+}
+
+impl<K> Default for Collection<K> {
+    fn default() -> Self {
+        Self { items: Vec::new() }
+    }
+}
+
+impl Collection<usize> {
+    pub fn indexed(len: usize) -> Self {
+        Self {
+            items: (0..len).map(CollectionItem::new).collect(),
+        }
+    }
+}
+
+impl<K> Collection<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn items(&self) -> &[CollectionItem<K>] {
+        &self.items
+    }
+
+    pub fn item_at(&self, index: usize) -> Option<&CollectionItem<K>> {
+        self.items.get(index)
+    }
+
+    pub fn key_at(&self, index: usize) -> Option<&K> {
+        self.items.get(index).map(CollectionItem::key)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.items.iter().map(CollectionItem::key)
+    }
+
+    pub fn texts(&self) -> Vec<&str> {
+        self.items.iter().map(CollectionItem::text).collect()
+    }
+
+    pub fn is_disabled_at(&self, index: usize) -> bool {
+        self.items.get(index).is_some_and(CollectionItem::disabled)
+    }
+
+    pub fn sections(&self) -> Vec<CollectionSection<'_, K>> {
+        let mut sections: Vec<CollectionSection<'_, K>> = Vec::new();
+        for item in &self.items {
+            match sections.last_mut() {
+                Some(section) if section.heading == item.section() => section.items.push(item),
+                _ => sections.push(CollectionSection {
+                    heading: item.section(),
+                    items: alloc::vec![item],
+                }),
+            }
+        }
+        sections
+    }
+}
+
+impl<K: PartialEq> Collection<K> {
+    pub fn from_items(items: Vec<CollectionItem<K>>) -> Self {
+        let mut collection = Self::new();
+        for item in items {
+            collection.push(item);
+        }
+        collection
+    }
+
+    pub fn index_of(&self, key: &K) -> Option<usize> {
+        self.items.iter().position(|item| &item.key == key)
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.index_of(key).is_some()
+    }
+
+    pub fn get(&self, key: &K) -> Option<&CollectionItem<K>> {
+        self.items.iter().find(|item| &item.key == key)
+    }
+
+    pub fn is_disabled(&self, key: &K) -> bool {
+        self.get(key).is_some_and(CollectionItem::disabled)
+    }
+
+    pub fn push(&mut self, item: CollectionItem<K>) -> bool {
+        if self.contains(&item.key) {
+            return false;
+        }
+        self.items.push(item);
+        true
+    }
+
+    pub fn insert(&mut self, index: usize, item: CollectionItem<K>) -> bool {
+        if self.contains(&item.key) {
+            return false;
+        }
+        let index = index.min(self.items.len());
+        self.items.insert(index, item);
+        true
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<CollectionItem<K>> {
+        let index = self.index_of(key)?;
+        Some(self.items.remove(index))
+    }
+
+    pub fn move_to(&mut self, key: &K, index: usize) -> bool {
+        let Some(from) = self.index_of(key) else {
+            return false;
+        };
+        let item = self.items.remove(from);
+        let index = index.min(self.items.len());
+        self.items.insert(index, item);
+        true
+    }
+
+    pub fn set_disabled(&mut self, key: &K, disabled: bool) -> bool {
+        match self.items.iter_mut().find(|item| &item.key == key) {
+            Some(item) if item.disabled != disabled => {
+                item.disabled = disabled;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<K: PartialEq> FromIterator<CollectionItem<K>> for Collection<K> {
+    fn from_iter<I: IntoIterator<Item = CollectionItem<K>>>(iter: I) -> Self {
+        Self::from_items(iter.into_iter().collect())
+    }
+}
+
+pub fn collection_resolve_key<K: PartialEq + Clone>(
+    previous: &Collection<K>,
+    next: &Collection<K>,
+    key: Option<&K>,
+) -> Option<K> {
+    collection_resolve_key_by(previous, next, key, |item| !item.disabled())
+}
+
+pub fn collection_resolve_key_by<K: PartialEq + Clone>(
+    previous: &Collection<K>,
+    next: &Collection<K>,
+    key: Option<&K>,
+    eligible: impl Fn(&CollectionItem<K>) -> bool,
+) -> Option<K> {
+    let key = key?;
+    if next.contains(key) {
+        return Some(key.clone());
+    }
+    if next.is_empty() {
+        return None;
+    }
+    let index = previous.index_of(key).unwrap_or(0).min(next.len() - 1);
+    (0..next.len())
+        .flat_map(|distance| [index.checked_add(distance), index.checked_sub(distance)])
+        .flatten()
+        .filter_map(|index| next.item_at(index))
+        .find(|item| eligible(item))
+        .map(|item| item.key().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{collection_resolve_key, collection_resolve_key_by, Collection, CollectionItem};
+    use std::vec::Vec;
+
+    fn fruits() -> Collection<&'static str> {
+        Collection::from_items(std::vec![
+            CollectionItem::new("apple").with_text("Apple").with_section("Fruit"),
+            CollectionItem::new("pear").with_text("Pear").with_section("Fruit"),
+            CollectionItem::new("kale")
+                .with_text("Kale")
+                .with_section("Greens")
+                .with_disabled(true),
+        ])
+    }
+
+    #[test]
+    fn collection_rejects_duplicate_keys_and_groups_sections() {
+        let mut collection = fruits();
+        assert!(!collection.push(CollectionItem::new("apple")));
+        assert_eq!(collection.texts(), ["Apple", "Pear", "Kale"]);
+        let headings: Vec<Option<&str>> =
+            collection.sections().iter().map(|section| section.heading).collect();
+        assert_eq!(headings, [Some("Fruit"), Some("Greens")]);
+        assert!(collection.is_disabled(&"kale"));
+        assert!(collection.move_to(&"kale", 0));
+        assert_eq!(collection.index_of(&"kale"), Some(0));
+    }
+
+    #[test]
+    fn collection_resolves_keys_across_changes() {
+        let previous = fruits();
+        let mut next = previous.clone();
+        next.insert(0, CollectionItem::new("fig"));
+        assert_eq!(collection_resolve_key(&previous, &next, Some(&"pear")), Some("pear"));
+
+        next.remove(&"pear");
+        assert_eq!(collection_resolve_key(&previous, &next, Some(&"pear")), Some("apple"));
+        assert_eq!(Collection::indexed(2).keys().copied().collect::<Vec<_>>(), [0, 1]);
+    }
+
+    #[test]
+    fn collection_resolve_skips_disabled_neighbours() {
+        let previous: Collection<char> = ['a', 'b', 'c']
+            .into_iter()
+            .map(|key| CollectionItem::new(key).with_disabled(key == 'b'))
+            .collect();
+        let mut next = previous.clone();
+        next.remove(&'a');
+        assert_eq!(collection_resolve_key(&previous, &next, Some(&'a')), Some('c'));
+        assert_eq!(
+            collection_resolve_key_by(&previous, &next, Some(&'a'), |_| true),
+            Some('b')
+        );
+
+        let mut tail = previous.clone();
+        tail.remove(&'c');
+        assert_eq!(collection_resolve_key(&previous, &tail, Some(&'c')), Some('a'));
+        tail.set_disabled(&'a', true);
+        assert_eq!(collection_resolve_key(&previous, &tail, Some(&'c')), None);
+    }
+}
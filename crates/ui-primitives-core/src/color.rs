@@ -0,0 +1,704 @@
+use alloc::string::String;
+
+use crate::aria::{Attr, AttrSet, Role};
+use crate::direction::{Direction, Directional};
+use crate::keyboard::{Key, KeyInput};
+use crate::orientation::Orientation;
+use crate::slider::{round_f64, slider_thumb_attrs, SliderAction, SliderModel};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsv {
+    pub h: f64,
+    pub s: f64,
+    pub v: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsl {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorParseError {
+    Empty,
+    InvalidLength,
+    InvalidDigit,
+}
+
+impl Rgb {
+    pub fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    pub fn from_hex(value: &str) -> Result<Self, ColorParseError> {
+        let digits = value.trim();
+        let digits = digits.strip_prefix('#').unwrap_or(digits);
+        if digits.is_empty() {
+            return Err(ColorParseError::Empty);
+        }
+        let bytes = digits.as_bytes();
+        match bytes.len() {
+            3 => {
+                let r = hex_digit(bytes[0])?;
+                let g = hex_digit(bytes[1])?;
+                let b = hex_digit(bytes[2])?;
+                Ok(Self::new(r * 17, g * 17, b * 17))
+            }
+            6 => {
+                let r = hex_digit(bytes[0])? * 16 + hex_digit(bytes[1])?;
+                let g = hex_digit(bytes[2])? * 16 + hex_digit(bytes[3])?;
+                let b = hex_digit(bytes[4])? * 16 + hex_digit(bytes[5])?;
+                Ok(Self::new(r, g, b))
+            }
+            _ => Err(ColorParseError::InvalidLength),
+        }
+    }
+
+    pub fn to_hex(self) -> String {
+        alloc::format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+impl Hsv {
+    pub fn new(h: f64, s: f64, v: f64) -> Self {
+        Self {
+            h: normalize_hue(h),
+            s: s.clamp(0.0, 1.0),
+            v: v.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Hsl {
+    pub fn new(h: f64, s: f64, l: f64) -> Self {
+        Self {
+            h: normalize_hue(h),
+            s: s.clamp(0.0, 1.0),
+            l: l.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl From<Rgb> for Hsv {
+    fn from(rgb: Rgb) -> Self {
+        let (r, g, b) = rgb_unit(rgb);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+        let s = if max == 0.0 { 0.0 } else { delta / max };
+        Hsv::new(rgb_hue(r, g, b, max, delta), s, max)
+    }
+}
+
+impl From<Rgb> for Hsl {
+    fn from(rgb: Rgb) -> Self {
+        let (r, g, b) = rgb_unit(rgb);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+        let l = (max + min) / 2.0;
+        let s = if delta == 0.0 {
+            0.0
+        } else {
+            delta / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        Hsl::new(rgb_hue(r, g, b, max, delta), s, l)
+    }
+}
+
+impl From<Hsv> for Rgb {
+    fn from(hsv: Hsv) -> Self {
+        let chroma = hsv.v * hsv.s;
+        hue_chroma_to_rgb(hsv.h, chroma, hsv.v - chroma)
+    }
+}
+
+impl From<Hsl> for Rgb {
+    fn from(hsl: Hsl) -> Self {
+        let chroma = (1.0 - (2.0 * hsl.l - 1.0).abs()) * hsl.s;
+        hue_chroma_to_rgb(hsl.h, chroma, hsl.l - chroma / 2.0)
+    }
+}
+
+impl From<Hsv> for Hsl {
+    fn from(hsv: Hsv) -> Self {
+        let l = hsv.v * (1.0 - hsv.s / 2.0);
+        let s = if l <= 0.0 || l >= 1.0 {
+            0.0
+        } else {
+            (hsv.v - l) / l.min(1.0 - l)
+        };
+        Hsl::new(hsv.h, s, l)
+    }
+}
+
+impl From<Hsl> for Hsv {
+    fn from(hsl: Hsl) -> Self {
+        let v = hsl.l + hsl.s * hsl.l.min(1.0 - hsl.l);
+        let s = if v <= 0.0 { 0.0 } else { 2.0 * (1.0 - hsl.l / v) };
+        Hsv::new(hsl.h, s, v)
+    }
+}
+
+pub fn color_description(color: Hsv) -> String {
+    if color.v < 0.1 {
+        return String::from("black");
+    }
+    if color.s < 0.1 {
+        let name = if color.v > 0.9 {
+            "white"
+        } else if color.v < 0.4 {
+            "dark gray"
+        } else if color.v > 0.7 {
+            "light gray"
+        } else {
+            "gray"
+        };
+        return String::from(name);
+    }
+    let hue = match color.h {
+        h if h < 15.0 => "red",
+        h if h < 45.0 => "orange",
+        h if h < 70.0 => "yellow",
+        h if h < 160.0 => "green",
+        h if h < 200.0 => "cyan",
+        h if h < 260.0 => "blue",
+        h if h < 290.0 => "purple",
+        h if h < 330.0 => "magenta",
+        _ => "red",
+    };
+    if color.v < 0.5 {
+        alloc::format!("dark {hue}")
+    } else if color.s < 0.5 && color.v > 0.7 {
+        alloc::format!("light {hue}")
+    } else {
+        String::from(hue)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorAreaAxis {
+    X,
+    Y,
+}
+
+pub fn color_area_action_from_key(
+    input: impl Into<KeyInput>,
+    direction: Direction,
+) -> Option<(ColorAreaAxis, SliderAction)> {
+    let input = input.into();
+    if input.is_composing || input.modifiers.has_command() {
+        return None;
+    }
+    let shift = input.modifiers.shift;
+    match direction.flip_key(input.key) {
+        Key::ArrowRight if shift => Some((ColorAreaAxis::X, SliderAction::IncrementPage)),
+        Key::ArrowLeft if shift => Some((ColorAreaAxis::X, SliderAction::DecrementPage)),
+        Key::ArrowUp if shift => Some((ColorAreaAxis::Y, SliderAction::IncrementPage)),
+        Key::ArrowDown if shift => Some((ColorAreaAxis::Y, SliderAction::DecrementPage)),
+        Key::ArrowRight => Some((ColorAreaAxis::X, SliderAction::Increment)),
+        Key::ArrowLeft => Some((ColorAreaAxis::X, SliderAction::Decrement)),
+        Key::ArrowUp => Some((ColorAreaAxis::Y, SliderAction::Increment)),
+        Key::ArrowDown => Some((ColorAreaAxis::Y, SliderAction::Decrement)),
+        Key::PageUp => Some((ColorAreaAxis::Y, SliderAction::IncrementPage)),
+        Key::PageDown => Some((ColorAreaAxis::Y, SliderAction::DecrementPage)),
+        Key::Home => Some((ColorAreaAxis::X, SliderAction::Min)),
+        Key::End => Some((ColorAreaAxis::X, SliderAction::Max)),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorAreaModel {
+    hue: f64,
+    x: SliderModel,
+    y: SliderModel,
+}
+
+impl ColorAreaModel {
+    pub fn new(color: Hsv) -> Self {
+        let x = SliderModel::new(color.s * 100.0, 0.0, 100.0);
+        let y = SliderModel::new(color.v * 100.0, 0.0, 100.0)
+            .with_orientation(Orientation::Vertical);
+        Self {
+            hue: normalize_hue(color.h),
+            x,
+            y,
+        }
+    }
+
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.x.set_direction(direction);
+        self
+    }
+
+    pub fn hue(&self) -> f64 {
+        self.hue
+    }
+
+    pub fn x(&self) -> &SliderModel {
+        &self.x
+    }
+
+    pub fn y(&self) -> &SliderModel {
+        &self.y
+    }
+
+    pub fn saturation(&self) -> f64 {
+        self.x.value() / 100.0
+    }
+
+    pub fn brightness(&self) -> f64 {
+        self.y.value() / 100.0
+    }
+
+    pub fn color(&self) -> Hsv {
+        Hsv::new(self.hue, self.saturation(), self.brightness())
+    }
+
+    pub fn rgb(&self) -> Rgb {
+        self.color().into()
+    }
+
+    pub fn disabled(&self) -> bool {
+        self.x.disabled()
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.x.set_disabled(disabled);
+        self.y.set_disabled(disabled);
+    }
+
+    pub fn set_hue(&mut self, hue: f64) -> bool {
+        let hue = normalize_hue(hue);
+        let changed = hue != self.hue;
+        self.hue = hue;
+        changed
+    }
+
+    pub fn set_color(&mut self, color: Hsv) -> bool {
+        let hue = self.set_hue(color.h);
+        let x = self.x.set_value(color.s * 100.0);
+        let y = self.y.set_value(color.v * 100.0);
+        hue || x || y
+    }
+
+    pub fn set_from_pointer(&mut self, x: f64, y: f64, width: f64, height: f64) -> bool {
+        let x = self.x.set_from_pointer(x, width);
+        let y = self.y.set_from_pointer(y, height);
+        x || y
+    }
+
+    pub fn apply(&mut self, axis: ColorAreaAxis, action: SliderAction) -> bool {
+        match axis {
+            ColorAreaAxis::X => self.x.apply(action),
+            ColorAreaAxis::Y => self.y.apply(action),
+        }
+    }
+
+    pub fn value_text(&self) -> String {
+        alloc::format!(
+            "Saturation {}%, Brightness {}%, {}",
+            self.x.value(),
+            self.y.value(),
+            color_description(self.color())
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorChannel {
+    Hue,
+    Saturation,
+    Brightness,
+    Lightness,
+    Red,
+    Green,
+    Blue,
+}
+
+impl ColorChannel {
+    pub fn label(self) -> &'static str {
+        match self {
+            ColorChannel::Hue => "Hue",
+            ColorChannel::Saturation => "Saturation",
+            ColorChannel::Brightness => "Brightness",
+            ColorChannel::Lightness => "Lightness",
+            ColorChannel::Red => "Red",
+            ColorChannel::Green => "Green",
+            ColorChannel::Blue => "Blue",
+        }
+    }
+
+    pub fn range(self) -> (f64, f64) {
+        match self {
+            ColorChannel::Hue => (0.0, 360.0),
+            ColorChannel::Saturation | ColorChannel::Brightness | ColorChannel::Lightness => {
+                (0.0, 100.0)
+            }
+            ColorChannel::Red | ColorChannel::Green | ColorChannel::Blue => (0.0, 255.0),
+        }
+    }
+
+    fn unit(self) -> &'static str {
+        match self {
+            ColorChannel::Hue => "°",
+            ColorChannel::Saturation | ColorChannel::Brightness | ColorChannel::Lightness => "%",
+            ColorChannel::Red | ColorChannel::Green | ColorChannel::Blue => "",
+        }
+    }
+
+    fn value_of(self, color: Hsv) -> f64 {
+        match self {
+            ColorChannel::Hue => color.h,
+            ColorChannel::Saturation => color.s * 100.0,
+            ColorChannel::Brightness => color.v * 100.0,
+            ColorChannel::Lightness => Hsl::from(color).l * 100.0,
+            ColorChannel::Red => Rgb::from(color).r as f64,
+            ColorChannel::Green => Rgb::from(color).g as f64,
+            ColorChannel::Blue => Rgb::from(color).b as f64,
+        }
+    }
+
+    fn with_value(self, color: Hsv, value: f64) -> Hsv {
+        match self {
+            ColorChannel::Hue => Hsv::new(value, color.s, color.v),
+            ColorChannel::Saturation => Hsv::new(color.h, value / 100.0, color.v),
+            ColorChannel::Brightness => Hsv::new(color.h, color.s, value / 100.0),
+            ColorChannel::Lightness => {
+                let hsl = Hsl::from(color);
+                Hsv::from(Hsl::new(hsl.h, hsl.s, value / 100.0))
+            }
+            ColorChannel::Red | ColorChannel::Green | ColorChannel::Blue => {
+                let mut rgb = Rgb::from(color);
+                let channel = round_f64(value.clamp(0.0, 255.0)) as u8;
+                match self {
+                    ColorChannel::Red => rgb.r = channel,
+                    ColorChannel::Green => rgb.g = channel,
+                    _ => rgb.b = channel,
+                }
+                let next = Hsv::from(rgb);
+                if next.s == 0.0 || next.v == 0.0 {
+                    Hsv::new(color.h, next.s, next.v)
+                } else {
+                    next
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorSliderModel {
+    channel: ColorChannel,
+    color: Hsv,
+    slider: SliderModel,
+}
+
+impl ColorSliderModel {
+    pub fn new(color: Hsv, channel: ColorChannel) -> Self {
+        let (min, max) = channel.range();
+        let slider = SliderModel::new(channel.value_of(color), min, max);
+        Self {
+            channel,
+            color,
+            slider,
+        }
+    }
+
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.slider = self.slider.with_orientation(orientation);
+        self
+    }
+
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.slider.set_direction(direction);
+        self
+    }
+
+    pub fn channel(&self) -> ColorChannel {
+        self.channel
+    }
+
+    pub fn color(&self) -> Hsv {
+        self.color
+    }
+
+    pub fn slider(&self) -> &SliderModel {
+        &self.slider
+    }
+
+    pub fn value(&self) -> f64 {
+        self.slider.value()
+    }
+
+    pub fn disabled(&self) -> bool {
+        self.slider.disabled()
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.slider.set_disabled(disabled);
+    }
+
+    pub fn set_color(&mut self, color: Hsv) -> bool {
+        let changed = color != self.color;
+        self.color = color;
+        let (min, max) = self.channel.range();
+        let disabled = self.slider.disabled();
+        let current = self.slider.value();
+        let value = if self.channel.with_value(color, current) == color {
+            current
+        } else {
+            self.channel.value_of(color)
+        };
+        self.slider = SliderModel::new(value, min, max)
+            .with_orientation(self.slider.orientation())
+            .with_direction(self.slider.direction());
+        self.slider.set_disabled(disabled);
+        changed
+    }
+
+    pub fn set_value(&mut self, value: f64) -> bool {
+        if !self.slider.set_value(value) {
+            return false;
+        }
+        self.sync_color();
+        true
+    }
+
+    pub fn set_from_pointer(&mut self, offset: f64, length: f64) -> bool {
+        if !self.slider.set_from_pointer(offset, length) {
+            return false;
+        }
+        self.sync_color();
+        true
+    }
+
+    pub fn apply(&mut self, action: SliderAction) -> bool {
+        if !self.slider.apply(action) {
+            return false;
+        }
+        self.sync_color();
+        true
+    }
+
+    pub fn value_text(&self) -> String {
+        alloc::format!(
+            "{} {}{}, {}",
+            self.channel.label(),
+            self.slider.value(),
+            self.channel.unit(),
+            color_description(self.color)
+        )
+    }
+
+    fn sync_color(&mut self) {
+        self.color = self.channel.with_value(self.color, self.slider.value());
+    }
+}
+
+fn hex_digit(byte: u8) -> Result<u8, ColorParseError> {
+    match byte {
+        b'0'..=b'9' => Ok(byte - b'0'),
+        b'a'..=b'f' => Ok(byte - b'a' + 10),
+        b'A'..=b'F' => Ok(byte - b'A' + 10),
+        _ => Err(ColorParseError::InvalidDigit),
+    }
+}
+
+fn normalize_hue(hue: f64) -> f64 {
+    if !hue.is_finite() {
+        return 0.0;
+    }
+    let hue = hue % 360.0;
+    if hue < 0.0 { hue + 360.0 } else { hue }
+}
+
+fn rgb_unit(rgb: Rgb) -> (f64, f64, f64) {
+    (
+        rgb.r as f64 / 255.0,
+        rgb.g as f64 / 255.0,
+        rgb.b as f64 / 255.0,
+    )
+}
+
+fn rgb_hue(r: f64, g: f64, b: f64, max: f64, delta: f64) -> f64 {
+    if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta % 6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    }
+}
+
+fn hue_chroma_to_rgb(hue: f64, chroma: f64, lightness: f64) -> Rgb {
+    let sector = normalize_hue(hue) / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match sector as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    Rgb::new(
+        unit_to_byte(r + lightness),
+        unit_to_byte(g + lightness),
+        unit_to_byte(b + lightness),
+    )
+}
+
+fn unit_to_byte(value: f64) -> u8 {
+    round_f64(value.clamp(0.0, 1.0) * 255.0) as u8
+}
+
+impl Directional for ColorAreaModel {
+    fn direction(&self) -> Direction {
+        self.x.direction()
+    }
+
+    fn set_direction(&mut self, direction: Direction) {
+        self.x.set_direction(direction);
+    }
+}
+
+impl Directional for ColorSliderModel {
+    fn direction(&self) -> Direction {
+        self.slider.direction()
+    }
+
+    fn set_direction(&mut self, direction: Direction) {
+        self.slider.set_direction(direction);
+    }
+}
+
+pub fn color_area_attrs(model: &ColorAreaModel) -> AttrSet {
+    AttrSet::new()
+        .with(Attr::Role(Role::Group))
+        .with(Attr::DataColor(model.rgb().to_hex()))
+        .with_if(model.disabled(), || Attr::DataDisabled(true))
+}
+
+pub fn color_area_x_attrs(model: &ColorAreaModel, label: Option<&str>) -> AttrSet {
+    color_area_thumb_attrs(model, model.x(), label.unwrap_or("Saturation"), true)
+}
+
+pub fn color_area_y_attrs(model: &ColorAreaModel, label: Option<&str>) -> AttrSet {
+    color_area_thumb_attrs(model, model.y(), label.unwrap_or("Brightness"), false)
+}
+
+pub fn color_slider_thumb_attrs(model: &ColorSliderModel, label: Option<&str>) -> AttrSet {
+    let value_text = model.value_text();
+    let label = label.unwrap_or(model.channel().label());
+    slider_thumb_attrs(model.slider(), Some(label), Some(&value_text))
+        .with(Attr::DataColor(Rgb::from(model.color()).to_hex()))
+}
+
+fn color_area_thumb_attrs(
+    model: &ColorAreaModel,
+    axis: &SliderModel,
+    label: &str,
+    focusable: bool,
+) -> AttrSet {
+    let value_text = model.value_text();
+    slider_thumb_attrs(axis, Some(label), Some(&value_text))
+        .with(Attr::AriaOrientation(axis.orientation()))
+        .with(Attr::AriaRoleDescription("2D slider".into()))
+        .with_if(!focusable, || Attr::TabIndex(-1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        color_area_action_from_key,
+        ColorAreaAxis,
+        ColorAreaModel,
+        ColorChannel,
+        ColorParseError,
+        ColorSliderModel,
+        Hsl,
+        Hsv,
+        Rgb,
+    };
+    use crate::direction::{Direction, Directional};
+    use crate::slider::SliderAction;
+
+    #[test]
+    fn rgb_hex_round_trip() {
+        assert_eq!(Rgb::from_hex("#1e90ff"), Ok(Rgb::new(30, 144, 255)));
+        assert_eq!(Rgb::from_hex("f0A"), Ok(Rgb::new(255, 0, 170)));
+        assert_eq!(Rgb::new(30, 144, 255).to_hex(), "#1e90ff");
+        assert_eq!(Rgb::from_hex("#12345"), Err(ColorParseError::InvalidLength));
+        assert_eq!(Rgb::from_hex("#zzz"), Err(ColorParseError::InvalidDigit));
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        let rgb = Rgb::new(30, 144, 255);
+        let hsv = Hsv::from(rgb);
+        let hsl = Hsl::from(rgb);
+        assert_eq!(Rgb::from(hsv), rgb);
+        assert_eq!(Rgb::from(hsl), rgb);
+        assert_eq!(Rgb::from(Hsl::from(hsv)), rgb);
+        assert_eq!(Rgb::from(Hsv::from(hsl)), rgb);
+        assert_eq!(Rgb::from(Hsv::new(0.0, 1.0, 1.0)), Rgb::new(255, 0, 0));
+    }
+
+    #[test]
+    fn color_area_moves_with_keys_and_pointer() {
+        let mut area = ColorAreaModel::new(Hsv::new(210.0, 0.5, 0.5));
+        let (axis, action) = color_area_action_from_key("ArrowUp", Direction::Ltr).expect("action");
+        assert_eq!(axis, ColorAreaAxis::Y);
+        assert!(area.apply(axis, action));
+        assert_eq!(area.brightness(), 0.51);
+        assert!(area.set_from_pointer(25.0, 0.0, 100.0, 100.0));
+        assert_eq!(area.saturation(), 0.25);
+        assert_eq!(area.brightness(), 1.0);
+        assert_eq!(area.value_text(), "Saturation 25%, Brightness 100%, light blue");
+
+        let mut area = area.with_direction(Direction::Rtl);
+        assert_eq!(
+            color_area_action_from_key("ArrowLeft", area.direction()),
+            Some((ColorAreaAxis::X, SliderAction::Increment))
+        );
+        assert!(area.set_from_pointer(25.0, 0.0, 100.0, 100.0));
+        assert_eq!(area.saturation(), 0.75);
+    }
+
+    #[test]
+    fn color_slider_updates_channel() {
+        let mut slider = ColorSliderModel::new(Hsv::new(0.0, 1.0, 1.0), ColorChannel::Green);
+        assert_eq!(slider.value(), 0.0);
+        slider.apply(SliderAction::Max);
+        assert_eq!(Rgb::from(slider.color()), Rgb::new(255, 255, 0));
+        assert_eq!(slider.value_text(), "Green 255, yellow");
+    }
+
+    #[test]
+    fn color_slider_keeps_hue_end_when_color_round_trips() {
+        let mut slider = ColorSliderModel::new(Hsv::new(120.0, 1.0, 1.0), ColorChannel::Hue);
+        assert!(slider.apply(SliderAction::Max));
+        assert_eq!(slider.value(), 360.0);
+        assert_eq!(slider.color().h, 0.0);
+        let color = slider.color();
+        assert!(!slider.set_color(color));
+        assert_eq!(slider.value(), 360.0);
+        assert!(slider.set_color(Hsv::new(0.0, 0.5, 1.0)));
+        assert_eq!(slider.value(), 360.0);
+        assert!(slider.set_color(Hsv::new(90.0, 0.5, 1.0)));
+        assert_eq!(slider.value(), 90.0);
+    }
+}
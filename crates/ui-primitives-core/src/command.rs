@@ -0,0 +1,566 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::aria::{Attr, AttrSet, AutoComplete, HasPopup, Role};
+use crate::keyboard::{Key, KeyInput};
+use crate::typeahead::typeahead_score;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandItem {
+    id: String,
+    label: String,
+    keywords: Vec<String>,
+    aliases: Vec<String>,
+    group: Option<String>,
+    page: Option<String>,
+    disabled: bool,
+}
+
+impl CommandItem {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            keywords: Vec::new(),
+            aliases: Vec::new(),
+            group: None,
+            page: None,
+            disabled: false,
+        }
+    }
+
+    pub fn with_keywords<I, K>(mut self, keywords: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<String>,
+    {
+        self.keywords = keywords.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_aliases<I, A>(mut self, aliases: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+        A: Into<String>,
+    {
+        self.aliases = aliases.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    pub fn with_page(mut self, page: impl Into<String>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    pub fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    pub fn group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    pub fn page(&self) -> Option<&str> {
+        self.page.as_deref()
+    }
+
+    pub fn disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandPage {
+    id: String,
+    title: String,
+    items: Vec<CommandItem>,
+}
+
+impl CommandPage {
+    pub fn new(id: impl Into<String>, title: impl Into<String>, items: Vec<CommandItem>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            items,
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn items(&self) -> &[CommandItem] {
+        &self.items
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandGroup<'a> {
+    pub heading: Option<&'a str>,
+    pub items: Vec<&'a CommandItem>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandAction {
+    Next,
+    Prev,
+    First,
+    Last,
+    Select,
+    Back,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandSelection {
+    Item(String),
+    Page(String),
+}
+
+pub fn command_action_from_key(
+    input: impl Into<KeyInput>,
+    query_empty: bool,
+) -> Option<CommandAction> {
+    let input = input.into();
+    if input.is_composing || input.modifiers.has_command() {
+        return None;
+    }
+    let shift = input.modifiers.shift;
+    match input.key {
+        Key::ArrowDown => Some(CommandAction::Next),
+        Key::ArrowUp => Some(CommandAction::Prev),
+        Key::Home if !shift => Some(CommandAction::First),
+        Key::End if !shift => Some(CommandAction::Last),
+        Key::Enter => Some(CommandAction::Select),
+        Key::Backspace if query_empty => Some(CommandAction::Back),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandModel {
+    pages: Vec<CommandPage>,
+    stack: Vec<usize>,
+    query: String,
+    results: Vec<usize>,
+    highlighted: Option<String>,
+    looped: bool,
+}
+
+impl CommandModel {
+    pub fn new(root: Vec<CommandItem>) -> Self {
+        let mut model = Self {
+            pages: alloc::vec![CommandPage::new("root", "", root)],
+            stack: alloc::vec![0],
+            query: String::new(),
+            results: Vec::new(),
+            highlighted: None,
+            looped: true,
+        };
+        model.refresh();
+        model
+    }
+
+    pub fn with_page(mut self, page: CommandPage) -> Self {
+        self.add_page(page);
+        self
+    }
+
+    pub fn add_page(&mut self, page: CommandPage) {
+        match self.pages.iter().position(|existing| existing.id == page.id) {
+            Some(index) => self.pages[index] = page,
+            None => self.pages.push(page),
+        }
+        self.refresh();
+    }
+
+    pub fn looped(&self) -> bool {
+        self.looped
+    }
+
+    pub fn set_looped(&mut self, looped: bool) {
+        self.looped = looped;
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn set_query(&mut self, query: impl Into<String>) {
+        self.query = query.into();
+        self.refresh();
+    }
+
+    pub fn page(&self) -> &CommandPage {
+        let index = self.stack.last().copied().unwrap_or(0);
+        &self.pages[index]
+    }
+
+    pub fn page_path(&self) -> Vec<&str> {
+        self.stack
+            .iter()
+            .map(|index| self.pages[*index].id.as_str())
+            .collect()
+    }
+
+    pub fn results(&self) -> Vec<&CommandItem> {
+        let items = self.page().items();
+        self.results.iter().map(|index| &items[*index]).collect()
+    }
+
+    pub fn groups(&self) -> Vec<CommandGroup<'_>> {
+        let mut groups: Vec<CommandGroup<'_>> = Vec::new();
+        for item in self.results() {
+            match groups.iter_mut().find(|group| group.heading == item.group()) {
+                Some(group) => group.items.push(item),
+                None => groups.push(CommandGroup {
+                    heading: item.group(),
+                    items: alloc::vec![item],
+                }),
+            }
+        }
+        groups
+    }
+
+    pub fn highlighted(&self) -> Option<&str> {
+        self.highlighted.as_deref()
+    }
+
+    pub fn highlighted_item(&self) -> Option<&CommandItem> {
+        let id = self.highlighted()?;
+        self.results().into_iter().find(|item| item.id() == id)
+    }
+
+    pub fn highlight(&mut self, id: Option<&str>) -> Option<&str> {
+        self.highlighted = id
+            .filter(|id| {
+                self.results()
+                    .iter()
+                    .any(|item| item.id() == *id && !item.disabled())
+            })
+            .map(String::from);
+        self.highlighted()
+    }
+
+    pub fn apply(&mut self, action: CommandAction) -> Option<CommandSelection> {
+        match action {
+            CommandAction::Next => self.move_highlight(true),
+            CommandAction::Prev => self.move_highlight(false),
+            CommandAction::First => {
+                self.highlighted = self.enabled_ids().first().cloned();
+            }
+            CommandAction::Last => {
+                self.highlighted = self.enabled_ids().last().cloned();
+            }
+            CommandAction::Select => return self.select_highlighted(),
+            CommandAction::Back => {
+                self.back();
+            }
+        }
+        None
+    }
+
+    pub fn select_highlighted(&mut self) -> Option<CommandSelection> {
+        let id = self.highlighted.clone()?;
+        self.select(&id)
+    }
+
+    pub fn select(&mut self, id: &str) -> Option<CommandSelection> {
+        let item = self
+            .page()
+            .items()
+            .iter()
+            .find(|item| item.id() == id && !item.disabled())?;
+        match item.page() {
+            Some(page_id) => {
+                let page_id = String::from(page_id);
+                let index = self.pages.iter().position(|page| page.id == page_id)?;
+                self.stack.push(index);
+                self.query.clear();
+                self.highlighted = None;
+                self.refresh();
+                Some(CommandSelection::Page(page_id))
+            }
+            None => Some(CommandSelection::Item(String::from(id))),
+        }
+    }
+
+    pub fn back(&mut self) -> bool {
+        if self.stack.len() <= 1 {
+            return false;
+        }
+        self.stack.pop();
+        self.query.clear();
+        self.highlighted = None;
+        self.refresh();
+        true
+    }
+
+    fn move_highlight(&mut self, forward: bool) {
+        let ids = self.enabled_ids();
+        if ids.is_empty() {
+            self.highlighted = None;
+            return;
+        }
+        let current = self
+            .highlighted
+            .as_ref()
+            .and_then(|id| ids.iter().position(|candidate| candidate == id));
+        let last = ids.len() - 1;
+        let next = match (current, forward) {
+            (None, true) => 0,
+            (None, false) => last,
+            (Some(index), true) if index < last => index + 1,
+            (Some(_), true) if self.looped => 0,
+            (Some(index), false) if index > 0 => index - 1,
+            (Some(_), false) if self.looped => last,
+            (Some(index), _) => index,
+        };
+        self.highlighted = Some(ids[next].clone());
+    }
+
+    fn enabled_ids(&self) -> Vec<String> {
+        self.results()
+            .into_iter()
+            .filter(|item| !item.disabled())
+            .map(|item| String::from(item.id()))
+            .collect()
+    }
+
+    fn refresh(&mut self) {
+        let items = self.page().items();
+        let mut scored: Vec<(usize, u32)> = items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| command_item_score(item, &self.query).map(|score| (index, score)))
+            .collect();
+        if !self.query.is_empty() {
+            scored.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        }
+
+        let mut group_order: Vec<Option<&str>> = Vec::new();
+        for (index, _) in &scored {
+            let group = items[*index].group();
+            if !group_order.contains(&group) {
+                group_order.push(group);
+            }
+        }
+        let mut results = Vec::with_capacity(scored.len());
+        for group in group_order {
+            results.extend(
+                scored
+                    .iter()
+                    .filter(|(index, _)| items[*index].group() == group)
+                    .map(|(index, _)| *index),
+            );
+        }
+        self.results = results;
+
+        let keep = self.highlighted.as_ref().is_some_and(|id| {
+            self.results()
+                .iter()
+                .any(|item| item.id() == id && !item.disabled())
+        });
+        if !keep {
+            self.highlighted = self.enabled_ids().first().cloned();
+        }
+    }
+}
+
+fn command_item_score(item: &CommandItem, query: &str) -> Option<u32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+    core::iter::once(item.label())
+        .chain(item.aliases().iter().map(String::as_str))
+        .chain(item.keywords().iter().map(String::as_str))
+        .filter_map(|text| typeahead_score(text, query))
+        .map(|score| score.score)
+        .max()
+}
+
+pub fn command_item_id(list_id: &str, item: &CommandItem) -> String {
+    alloc::format!("{}-{}", list_id, item.id())
+}
+
+pub fn command_input_attrs(model: &CommandModel, list_id: &str, label: Option<&str>) -> AttrSet {
+    AttrSet::new()
+        .with(Attr::Role(Role::Combobox))
+        .with(Attr::AriaExpanded(true))
+        .with(Attr::AriaAutoComplete(AutoComplete::List))
+        .with(Attr::AriaControls(list_id.into()))
+        .with(Attr::AutoCompleteOff)
+        .with(Attr::SpellCheck(false))
+        .with_opt(model.highlighted_item(), |item| {
+            Attr::AriaActiveDescendant(command_item_id(list_id, item))
+        })
+        .with_opt(label, |label| Attr::AriaLabel(label.into()))
+}
+
+pub fn command_list_attrs(list_id: &str, label: Option<&str>) -> AttrSet {
+    AttrSet::new()
+        .with(Attr::Role(Role::Listbox))
+        .with(Attr::Id(list_id.into()))
+        .with_opt(label, |label| Attr::AriaLabel(label.into()))
+}
+
+pub fn command_group_attrs(heading: Option<&str>) -> AttrSet {
+    AttrSet::new()
+        .with(Attr::Role(Role::Group))
+        .with_opt(heading, |heading| Attr::AriaLabel(heading.into()))
+}
+
+pub fn command_group_heading_attrs() -> AttrSet {
+    AttrSet::new().with(Attr::AriaHidden(true))
+}
+
+pub fn command_item_attrs(model: &CommandModel, item: &CommandItem, list_id: &str) -> AttrSet {
+    let highlighted = model.highlighted() == Some(item.id());
+    AttrSet::new()
+        .with(Attr::Role(Role::Option))
+        .with(Attr::Id(command_item_id(list_id, item)))
+        .with(Attr::AriaSelected(highlighted))
+        .with_if(highlighted, || Attr::DataHighlighted(true))
+        .with_if(item.disabled(), || Attr::AriaDisabled(true))
+        .with_if(item.disabled(), || Attr::DataDisabled(true))
+        .with_if(item.page().is_some(), || Attr::AriaHasPopup(HasPopup::True))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        command_action_from_key,
+        command_group_attrs,
+        command_group_heading_attrs,
+        command_input_attrs,
+        CommandAction,
+        CommandItem,
+        CommandModel,
+        CommandPage,
+        CommandSelection,
+    };
+    use crate::aria::Attr;
+    use crate::keyboard::{KeyInput, Modifiers};
+    use std::vec;
+    use std::vec::Vec;
+
+    fn palette() -> CommandModel {
+        CommandModel::new(vec![
+            CommandItem::new("new-file", "New File").with_group("File"),
+            CommandItem::new("open", "Open Recent").with_group("File"),
+            CommandItem::new("theme", "Theme").with_group("Preferences").with_page("themes"),
+            CommandItem::new("settings", "Settings")
+                .with_group("Preferences")
+                .with_aliases(["knobs"])
+                .with_keywords(["preferences", "config"]),
+        ])
+        .with_page(CommandPage::new(
+            "themes",
+            "Theme",
+            vec![
+                CommandItem::new("light", "Light"),
+                CommandItem::new("dark", "Dark"),
+            ],
+        ))
+    }
+
+    #[test]
+    fn command_filters_with_keywords_and_groups() {
+        let mut model = palette();
+        model.set_query("cfg");
+        let ids: Vec<&str> = model.results().iter().map(|item| item.id()).collect();
+        assert_eq!(ids, vec!["settings"]);
+        model.set_query("knob");
+        let ids: Vec<&str> = model.results().iter().map(|item| item.id()).collect();
+        assert_eq!(ids, vec!["settings"]);
+
+        model.set_query("");
+        let headings: Vec<Option<&str>> = model.groups().iter().map(|group| group.heading).collect();
+        assert_eq!(headings, vec![Some("File"), Some("Preferences")]);
+    }
+
+    #[test]
+    fn command_ranks_prefix_before_subsequence() {
+        let mut model = palette();
+        model.set_query("o");
+        let ids: Vec<&str> = model.results().iter().map(|item| item.id()).collect();
+        assert_eq!(ids, vec!["open", "settings"]);
+    }
+
+    #[test]
+    fn command_keeps_highlight_stable() {
+        let mut model = palette();
+        model.apply(CommandAction::Next);
+        assert_eq!(model.highlighted(), Some("open"));
+        model.set_query("e");
+        assert_eq!(model.highlighted(), Some("open"));
+        model.set_query("set");
+        assert_eq!(model.highlighted(), Some("settings"));
+    }
+
+    #[test]
+    fn command_pages_push_and_pop() {
+        let mut model = palette();
+        model.set_query("them");
+        assert_eq!(
+            model.apply(CommandAction::Select),
+            Some(CommandSelection::Page("themes".into()))
+        );
+        assert_eq!(model.page_path(), vec!["root", "themes"]);
+        assert_eq!(model.highlighted(), Some("light"));
+        assert_eq!(command_action_from_key("Backspace", true), Some(CommandAction::Back));
+        assert_eq!(command_action_from_key("Backspace", false), None);
+        let with = |key: &str, modifiers| KeyInput::from(key).with_modifiers(modifiers);
+        assert_eq!(command_action_from_key(with("Backspace", Modifiers::CTRL), true), None);
+        assert_eq!(command_action_from_key(with("Enter", Modifiers::CTRL), true), None);
+        assert_eq!(command_action_from_key(with("Home", Modifiers::SHIFT), true), None);
+        assert_eq!(command_action_from_key(with("End", Modifiers::SHIFT), true), None);
+        assert_eq!(
+            command_action_from_key(with("ArrowDown", Modifiers::SHIFT), true),
+            Some(CommandAction::Next)
+        );
+        model.apply(CommandAction::Back);
+        assert_eq!(model.page().id(), "root");
+    }
+
+    #[test]
+    fn command_attrs_label_groups_and_point_at_item_ids() {
+        let mut model = palette();
+        model.apply(CommandAction::Next);
+        let item = model.highlighted_item().expect("highlighted");
+        assert_eq!(item.id(), "open");
+        assert_eq!(
+            command_input_attrs(&model, "cmd", None).get("aria-activedescendant"),
+            Some(&Attr::AriaActiveDescendant("cmd-open".into()))
+        );
+        let group = command_group_attrs(Some("File"));
+        assert_eq!(group.get("aria-label"), Some(&Attr::AriaLabel("File".into())));
+        assert_eq!(group.get("aria-labelledby"), None);
+        assert_eq!(command_group_heading_attrs().get("role"), None);
+    }
+}
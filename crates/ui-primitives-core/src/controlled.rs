@@ -1,17 +1,223 @@
 use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::cell::RefCell;
 use core::mem;
 
+pub const CHANGE_QUEUE_LIMIT: usize = 256;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChangeSource {
+    #[default]
+    Programmatic,
+    Keyboard,
+    Pointer,
+    Custom(&'static str),
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Change<'a, T> {
     pub previous: &'a T,
     pub next: &'a T,
+    pub source: ChangeSource,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeDecision<T> {
+    Accept,
+    Reject,
+    Replace(T),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeOutcome {
+    Committed,
+    Replaced,
+    Rejected,
+    Unchanged,
+}
+
+impl ChangeOutcome {
+    pub fn is_committed(&self) -> bool {
+        matches!(self, Self::Committed | Self::Replaced)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueueFlush {
+    pub outcomes: Vec<ChangeOutcome>,
+    pub remaining: usize,
+}
+
+impl QueueFlush {
+    pub fn applied(&self) -> usize {
+        self.outcomes.len()
+    }
+
+    pub fn overflowed(&self) -> bool {
+        self.remaining > 0
+    }
 }
 
 pub type OnChange<T> = Box<dyn for<'a> FnMut(Change<'a, T>)>;
+pub type BeforeChange<T> = Box<dyn for<'a> FnMut(Change<'a, T>) -> ChangeDecision<T>>;
+
+struct Interceptor<T> {
+    before_change: BeforeChange<T>,
+    same: fn(&T, &T) -> bool,
+}
+
+impl<T: PartialEq> Interceptor<T> {
+    fn new(before_change: BeforeChange<T>) -> Self {
+        Self {
+            before_change,
+            same: T::eq,
+        }
+    }
+}
+
+fn intercept_change<T>(
+    interceptor: &mut Option<Interceptor<T>>,
+    previous: &T,
+    next: T,
+    source: ChangeSource,
+) -> (Option<T>, ChangeOutcome) {
+    let Some(interceptor) = interceptor.as_mut() else {
+        return (Some(next), ChangeOutcome::Committed);
+    };
+    let decision = (interceptor.before_change)(Change {
+        previous,
+        next: &next,
+        source,
+    });
+    match decision {
+        ChangeDecision::Accept => (Some(next), ChangeOutcome::Committed),
+        ChangeDecision::Reject => (None, ChangeOutcome::Rejected),
+        ChangeDecision::Replace(value) if (interceptor.same)(previous, &value) => {
+            (None, ChangeOutcome::Unchanged)
+        }
+        ChangeDecision::Replace(value) => (Some(value), ChangeOutcome::Replaced),
+    }
+}
+
+type Listener<T> = Rc<RefCell<dyn for<'a> FnMut(Change<'a, T>)>>;
+
+struct Subscribers<T> {
+    next_id: u64,
+    entries: Vec<(u64, Listener<T>)>,
+}
+
+impl<T> Subscribers<T> {
+    fn contains(&self, id: u64) -> bool {
+        self.entries.iter().any(|(entry, _)| *entry == id)
+    }
+}
+
+pub struct Subscription {
+    unsubscribe: Option<Box<dyn FnOnce()>>,
+}
+
+impl Subscription {
+    pub fn unsubscribe(self) {}
+
+    pub fn detach(mut self) {
+        self.unsubscribe = None;
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some(unsubscribe) = self.unsubscribe.take() {
+            unsubscribe();
+        }
+    }
+}
+
+impl core::fmt::Debug for Subscription {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Subscription")
+            .field("active", &self.unsubscribe.is_some())
+            .finish()
+    }
+}
+
+pub struct ChangeQueue<T> {
+    pending: Rc<RefCell<VecDeque<(T, ChangeSource)>>>,
+}
+
+impl<T> Clone for ChangeQueue<T> {
+    fn clone(&self) -> Self {
+        Self {
+            pending: Rc::clone(&self.pending),
+        }
+    }
+}
+
+impl<T> ChangeQueue<T> {
+    pub fn set(&self, next: T) {
+        self.set_with_source(next, ChangeSource::Programmatic);
+    }
+
+    pub fn set_with_source(&self, next: T, source: ChangeSource) {
+        self.pending.borrow_mut().push_back((next, source));
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.borrow().is_empty()
+    }
+
+    fn pop(&self) -> Option<(T, ChangeSource)> {
+        self.pending.borrow_mut().pop_front()
+    }
+}
 
 pub struct Controlled<T> {
     value: T,
     on_change: Option<OnChange<T>>,
+    before_change: Option<Interceptor<T>>,
+    subscribers: Rc<RefCell<Subscribers<T>>>,
+    queue: ChangeQueue<T>,
+    last_flush: QueueFlush,
+}
+
+impl<T: PartialEq> Controlled<T> {
+    pub fn with_before_change(mut self, before_change: BeforeChange<T>) -> Self {
+        self.set_before_change(Some(before_change));
+        self
+    }
+
+    pub fn set_before_change(&mut self, before_change: Option<BeforeChange<T>>) {
+        self.before_change = before_change.map(Interceptor::new);
+    }
+}
+
+impl<T: 'static> Controlled<T> {
+    pub fn subscribe(
+        &mut self,
+        listener: impl for<'a> FnMut(Change<'a, T>) + 'static,
+    ) -> Subscription {
+        let id = {
+            let mut subscribers = self.subscribers.borrow_mut();
+            let id = subscribers.next_id;
+            subscribers.next_id += 1;
+            let listener: Listener<T> = Rc::new(RefCell::new(listener));
+            subscribers.entries.push((id, listener));
+            id
+        };
+        let subscribers = Rc::downgrade(&self.subscribers);
+        Subscription {
+            unsubscribe: Some(Box::new(move || {
+                if let Some(subscribers) = subscribers.upgrade() {
+                    subscribers.borrow_mut().entries.retain(|(entry, _)| *entry != id);
+                }
+            })),
+        }
+    }
 }
 
 impl<T> Controlled<T> {
@@ -19,14 +225,22 @@ impl<T> Controlled<T> {
         Self {
             value,
             on_change: None,
+            before_change: None,
+            subscribers: Rc::new(RefCell::new(Subscribers {
+                next_id: 0,
+                entries: Vec::new(),
+            })),
+            queue: ChangeQueue {
+                pending: Rc::new(RefCell::new(VecDeque::new())),
+            },
+            last_flush: QueueFlush::default(),
         }
     }
 
     pub fn with_on_change(value: T, on_change: OnChange<T>) -> Self {
-        Self {
-            value,
-            on_change: Some(on_change),
-        }
+        let mut controlled = Self::new(value);
+        controlled.on_change = Some(on_change);
+        controlled
     }
 
     pub fn value(&self) -> &T {
@@ -41,15 +255,73 @@ impl<T> Controlled<T> {
         self.on_change = on_change;
     }
 
-    pub fn set(&mut self, next: T) {
+    pub fn set(&mut self, next: T) -> ChangeOutcome {
+        self.set_with_source(next, ChangeSource::Programmatic)
+    }
+
+    pub fn set_with_source(&mut self, next: T, source: ChangeSource) -> ChangeOutcome {
+        let outcome = self.commit(next, source);
+        self.last_flush = self.flush_queue();
+        outcome
+    }
+
+    pub fn queue(&self) -> ChangeQueue<T> {
+        self.queue.clone()
+    }
+
+    pub fn flush_queue(&mut self) -> QueueFlush {
+        let mut outcomes = Vec::new();
+        while outcomes.len() < CHANGE_QUEUE_LIMIT {
+            let Some((next, source)) = self.queue.pop() else {
+                break;
+            };
+            outcomes.push(self.commit(next, source));
+        }
+        QueueFlush {
+            outcomes,
+            remaining: self.queue.len(),
+        }
+    }
+
+    pub fn last_flush(&self) -> &QueueFlush {
+        &self.last_flush
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        self.subscribers.borrow().entries.len()
+    }
+
+    fn commit(&mut self, next: T, source: ChangeSource) -> ChangeOutcome {
+        let (next, outcome) = intercept_change(&mut self.before_change, &self.value, next, source);
+        let Some(next) = next else {
+            return outcome;
+        };
         let previous = mem::replace(&mut self.value, next);
+        let change = || Change {
+            previous: &previous,
+            next: &self.value,
+            source,
+        };
         if let Some(on_change) = self.on_change.as_mut() {
-            let change = Change {
-                previous: &previous,
-                next: &self.value,
-            };
-            on_change(change);
+            on_change(change());
+        }
+
+        let listeners: Vec<(u64, Listener<T>)> = self
+            .subscribers
+            .borrow()
+            .entries
+            .iter()
+            .map(|(id, listener)| (*id, Rc::clone(listener)))
+            .collect();
+        for (id, listener) in listeners {
+            if !self.subscribers.borrow().contains(id) {
+                continue;
+            }
+            if let Ok(mut listener) = listener.try_borrow_mut() {
+                (*listener)(change());
+            }
         }
+        outcome
     }
 
     pub fn set_if_changed(&mut self, next: T) -> bool
@@ -59,13 +331,21 @@ impl<T> Controlled<T> {
         if self.value == next {
             return false;
         }
-        self.set(next);
-        true
+        self.set(next).is_committed()
+    }
+
+    pub fn update(&mut self, f: impl FnOnce(&T) -> T) -> ChangeOutcome {
+        let next = f(&self.value);
+        self.set(next)
     }
 
-    pub fn update(&mut self, f: impl FnOnce(&T) -> T) {
+    pub fn update_with_source(
+        &mut self,
+        f: impl FnOnce(&T) -> T,
+        source: ChangeSource,
+    ) -> ChangeOutcome {
         let next = f(&self.value);
-        self.set(next);
+        self.set_with_source(next, source)
     }
 
     pub fn update_if_changed(&mut self, f: impl FnOnce(&T) -> T) -> bool
@@ -77,9 +357,162 @@ impl<T> Controlled<T> {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlMode {
+    Controlled,
+    Uncontrolled,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ControlModeSwitch {
+    pub from: ControlMode,
+    pub to: ControlMode,
+}
+
+impl ControlModeSwitch {
+    pub fn message(&self) -> &'static str {
+        match self.to {
+            ControlMode::Controlled => {
+                "A component is changing an uncontrolled value to be controlled. Decide between controlled and uncontrolled for the lifetime of the component."
+            }
+            ControlMode::Uncontrolled => {
+                "A component is changing a controlled value to be uncontrolled. Decide between controlled and uncontrolled for the lifetime of the component."
+            }
+        }
+    }
+}
+
+pub struct ControllableState<T> {
+    prop: Option<T>,
+    internal: T,
+    on_change: Option<OnChange<T>>,
+    before_change: Option<Interceptor<T>>,
+}
+
+impl<T: PartialEq> ControllableState<T> {
+    pub fn with_before_change(mut self, before_change: BeforeChange<T>) -> Self {
+        self.set_before_change(Some(before_change));
+        self
+    }
+
+    pub fn set_before_change(&mut self, before_change: Option<BeforeChange<T>>) {
+        self.before_change = before_change.map(Interceptor::new);
+    }
+}
+
+impl<T> ControllableState<T> {
+    pub fn new(prop: Option<T>, default: T) -> Self {
+        Self {
+            prop,
+            internal: default,
+            on_change: None,
+            before_change: None,
+        }
+    }
+
+    pub fn with_on_change(mut self, on_change: OnChange<T>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+
+    pub fn set_on_change(&mut self, on_change: Option<OnChange<T>>) {
+        self.on_change = on_change;
+    }
+
+    pub fn mode(&self) -> ControlMode {
+        if self.prop.is_some() {
+            ControlMode::Controlled
+        } else {
+            ControlMode::Uncontrolled
+        }
+    }
+
+    pub fn is_controlled(&self) -> bool {
+        self.prop.is_some()
+    }
+
+    pub fn value(&self) -> &T {
+        self.prop.as_ref().unwrap_or(&self.internal)
+    }
+
+    pub fn sync(&mut self, prop: Option<T>) -> Option<ControlModeSwitch> {
+        let from = self.mode();
+        match (self.prop.take(), prop) {
+            (Some(previous), None) => self.internal = previous,
+            (_, prop) => self.prop = prop,
+        }
+        let to = self.mode();
+        (from != to).then_some(ControlModeSwitch { from, to })
+    }
+
+    pub fn set(&mut self, next: T) -> ChangeOutcome {
+        self.set_with_source(next, ChangeSource::Programmatic)
+    }
+
+    pub fn set_with_source(&mut self, next: T, source: ChangeSource) -> ChangeOutcome {
+        let current = self.prop.as_ref().unwrap_or(&self.internal);
+        let (next, outcome) = intercept_change(&mut self.before_change, current, next, source);
+        let Some(next) = next else {
+            return outcome;
+        };
+        if let Some(prop) = self.prop.as_ref() {
+            if let Some(on_change) = self.on_change.as_mut() {
+                on_change(Change {
+                    previous: prop,
+                    next: &next,
+                    source,
+                });
+            }
+            return outcome;
+        }
+        let previous = mem::replace(&mut self.internal, next);
+        if let Some(on_change) = self.on_change.as_mut() {
+            on_change(Change {
+                previous: &previous,
+                next: &self.internal,
+                source,
+            });
+        }
+        outcome
+    }
+
+    pub fn set_if_changed(&mut self, next: T) -> bool
+    where
+        T: PartialEq,
+    {
+        if self.value() == &next {
+            return false;
+        }
+        self.set(next).is_committed()
+    }
+
+    pub fn update(&mut self, f: impl FnOnce(&T) -> T) -> ChangeOutcome {
+        let next = f(self.value());
+        self.set(next)
+    }
+
+    pub fn update_if_changed(&mut self, f: impl FnOnce(&T) -> T) -> bool
+    where
+        T: PartialEq,
+    {
+        let next = f(self.value());
+        self.set_if_changed(next)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Change, Controlled};
+    use super::{
+        Change,
+        ChangeDecision,
+        ChangeOutcome,
+        ChangeSource,
+        ControlMode,
+        ControlModeSwitch,
+        Controlled,
+        ControllableState,
+        CHANGE_QUEUE_LIMIT,
+    };
     use std::cell::RefCell;
     use std::boxed::Box;
     use std::rc::Rc;
@@ -130,4 +563,201 @@ mod tests {
         controlled.update(|value| value + 5);
         assert_eq!(*controlled.value(), 15);
     }
+
+    #[test]
+    fn controllable_uncontrolled_owns_value() {
+        let changes: Rc<RefCell<Vec<(i32, i32)>>> = Rc::new(RefCell::new(Vec::new()));
+        let changes_handle = Rc::clone(&changes);
+        let mut state = ControllableState::new(None, 1).with_on_change(Box::new(
+            move |change: Change<'_, i32>| {
+                changes_handle
+                    .borrow_mut()
+                    .push((*change.previous, *change.next));
+            },
+        ));
+
+        assert!(state.set_if_changed(4));
+        assert_eq!(*state.value(), 4);
+        assert_eq!(state.mode(), ControlMode::Uncontrolled);
+        assert_eq!(changes.borrow().as_slice(), &[(1, 4)]);
+    }
+
+    #[test]
+    fn controllable_controlled_routes_through_on_change() {
+        let changes: Rc<RefCell<Vec<(i32, i32)>>> = Rc::new(RefCell::new(Vec::new()));
+        let changes_handle = Rc::clone(&changes);
+        let mut state = ControllableState::new(Some(7), 0).with_on_change(Box::new(
+            move |change: Change<'_, i32>| {
+                changes_handle
+                    .borrow_mut()
+                    .push((*change.previous, *change.next));
+            },
+        ));
+
+        state.update(|value| value + 1);
+        assert_eq!(*state.value(), 7);
+        assert_eq!(changes.borrow().as_slice(), &[(7, 8)]);
+        assert_eq!(state.sync(Some(8)), None);
+        assert_eq!(*state.value(), 8);
+    }
+
+    #[test]
+    fn controllable_reports_mode_switch() {
+        let mut state = ControllableState::new(None, 0);
+        let switch = state.sync(Some(3)).expect("switch");
+        assert_eq!(
+            switch,
+            ControlModeSwitch {
+                from: ControlMode::Uncontrolled,
+                to: ControlMode::Controlled,
+            }
+        );
+        assert!(switch.message().contains("uncontrolled value to be controlled"));
+        assert!(state.sync(None).is_some());
+        assert_eq!(*state.value(), 3);
+    }
+
+    #[test]
+    fn controlled_before_change_rejects_and_replaces() {
+        let sources: Rc<RefCell<Vec<ChangeSource>>> = Rc::new(RefCell::new(Vec::new()));
+        let sources_handle = Rc::clone(&sources);
+        let mut controlled = Controlled::with_on_change(
+            50,
+            Box::new(move |change: Change<'_, i32>| {
+                sources_handle.borrow_mut().push(change.source);
+            }),
+        )
+        .with_before_change(Box::new(|change: Change<'_, i32>| {
+            if change.source == ChangeSource::Custom("locked") {
+                ChangeDecision::Reject
+            } else if *change.next > 100 {
+                ChangeDecision::Replace(100)
+            } else {
+                ChangeDecision::Accept
+            }
+        }));
+
+        assert_eq!(
+            controlled.set_with_source(10, ChangeSource::Custom("locked")),
+            ChangeOutcome::Rejected
+        );
+        assert_eq!(*controlled.value(), 50);
+        assert_eq!(
+            controlled.update_with_source(|value| value + 80, ChangeSource::Keyboard),
+            ChangeOutcome::Replaced
+        );
+        assert_eq!(*controlled.value(), 100);
+        assert_eq!(controlled.set(70), ChangeOutcome::Committed);
+        assert_eq!(
+            sources.borrow().as_slice(),
+            &[ChangeSource::Keyboard, ChangeSource::Programmatic]
+        );
+    }
+
+    #[test]
+    fn controlled_replace_with_current_value_is_unchanged() {
+        let seen: Rc<RefCell<Vec<i32>>> = Rc::new(RefCell::new(Vec::new()));
+        let mut controlled = Controlled::new(100).with_before_change(Box::new(
+            |change: Change<'_, i32>| ChangeDecision::Replace((*change.next).min(100)),
+        ));
+        let seen_handle = Rc::clone(&seen);
+        let _subscription = controlled.subscribe(move |change: Change<'_, i32>| {
+            seen_handle.borrow_mut().push(*change.next);
+        });
+
+        assert!(!controlled.set_if_changed(150));
+        assert_eq!(controlled.set(120), ChangeOutcome::Unchanged);
+        assert!(controlled.set_if_changed(40));
+        assert_eq!(seen.borrow().as_slice(), &[40]);
+    }
+
+    #[test]
+    fn controllable_before_change_intercepts_both_modes() {
+        let changes: Rc<RefCell<Vec<(i32, i32)>>> = Rc::new(RefCell::new(Vec::new()));
+        let changes_handle = Rc::clone(&changes);
+        let mut state = ControllableState::new(None, 5)
+            .with_on_change(Box::new(move |change: Change<'_, i32>| {
+                changes_handle
+                    .borrow_mut()
+                    .push((*change.previous, *change.next));
+            }))
+            .with_before_change(Box::new(|change: Change<'_, i32>| {
+                if *change.next < 0 {
+                    ChangeDecision::Reject
+                } else {
+                    ChangeDecision::Replace((*change.next).min(10))
+                }
+            }));
+
+        assert_eq!(state.set(-1), ChangeOutcome::Rejected);
+        assert_eq!(state.set(20), ChangeOutcome::Replaced);
+        assert_eq!(*state.value(), 10);
+        assert!(!state.set_if_changed(30));
+
+        state.sync(Some(3));
+        assert_eq!(state.set(3), ChangeOutcome::Unchanged);
+        assert_eq!(state.set(50), ChangeOutcome::Replaced);
+        assert_eq!(*state.value(), 3);
+        assert_eq!(changes.borrow().as_slice(), &[(5, 10), (3, 10)]);
+    }
+
+    #[test]
+    fn controlled_notifies_subscribers_in_order_until_dropped() {
+        let log: Rc<RefCell<Vec<(&str, i32)>>> = Rc::new(RefCell::new(Vec::new()));
+        let mut controlled = Controlled::new(0);
+        let first_log = Rc::clone(&log);
+        let first = controlled.subscribe(move |change: Change<'_, i32>| {
+            first_log.borrow_mut().push(("analytics", *change.next));
+        });
+        let second_log = Rc::clone(&log);
+        let _second = controlled.subscribe(move |change: Change<'_, i32>| {
+            second_log.borrow_mut().push(("persist", *change.next));
+        });
+
+        controlled.set(1);
+        drop(first);
+        controlled.set(2);
+
+        assert_eq!(controlled.subscriber_count(), 1);
+        assert_eq!(
+            log.borrow().as_slice(),
+            &[("analytics", 1), ("persist", 1), ("persist", 2)]
+        );
+    }
+
+    #[test]
+    fn controlled_queues_reentrant_sets() {
+        let seen: Rc<RefCell<Vec<i32>>> = Rc::new(RefCell::new(Vec::new()));
+        let mut controlled = Controlled::new(0);
+        let queue = controlled.queue();
+        let seen_handle = Rc::clone(&seen);
+        let _subscription = controlled.subscribe(move |change: Change<'_, i32>| {
+            seen_handle.borrow_mut().push(*change.next);
+            if *change.next % 2 == 1 {
+                queue.set(*change.next + 1);
+            }
+        });
+
+        assert_eq!(controlled.set(3), ChangeOutcome::Committed);
+        assert_eq!(*controlled.value(), 4);
+        assert_eq!(seen.borrow().as_slice(), &[3, 4]);
+        assert!(controlled.queue().is_empty());
+        assert_eq!(controlled.last_flush().outcomes, [ChangeOutcome::Committed]);
+    }
+
+    #[test]
+    fn controlled_flush_reports_queue_overflow() {
+        let mut controlled = Controlled::new(0);
+        let queue = controlled.queue();
+        let _subscription = controlled.subscribe(move |change: Change<'_, i32>| {
+            queue.set(*change.next + 1);
+        });
+
+        controlled.set(1);
+        let flush = controlled.last_flush();
+        assert_eq!(flush.applied(), CHANGE_QUEUE_LIMIT);
+        assert!(flush.overflowed());
+        assert_eq!(flush.remaining, 1);
+        assert_eq!(*controlled.value(), 1 + CHANGE_QUEUE_LIMIT as i32);
+    }
 }
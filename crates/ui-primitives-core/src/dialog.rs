@@ -1,9 +1,22 @@
+use crate::aria::{Attr, AttrSet, DataState, HasPopup, Role};
+use crate::keyboard::{Key, KeyInput};
+use crate::model::{Effect, Model, Outcome};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DialogState {
     Open,
     Closed,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialogEvent {
+    Toggle,
+    SetOpen(bool),
+    Key(KeyInput),
+    PointerDownOutside,
+    FocusOutside,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DialogModel {
     open: bool,
@@ -47,9 +60,59 @@ impl DialogModel {
     }
 }
 
+impl Model for DialogModel {
+    type Event = DialogEvent;
+    type Key = ();
+
+    fn send(&mut self, event: DialogEvent) -> Outcome {
+        let mut outcome = Outcome::none();
+        let changed = match event {
+            DialogEvent::Toggle => self.toggle(),
+            DialogEvent::SetOpen(open) => self.set_open(open),
+            DialogEvent::Key(input) => {
+                if !self.open || !input.is(&Key::Escape) || input.is_composing {
+                    return outcome;
+                }
+                outcome.push(Effect::PreventDefault);
+                self.set_open(false)
+            }
+            DialogEvent::PointerDownOutside => self.open && self.set_open(false),
+            DialogEvent::FocusOutside => self.open && !self.modal && self.set_open(false),
+        };
+        if changed {
+            outcome.push(Effect::OpenChanged(self.open));
+        }
+        outcome
+    }
+}
+
+pub fn dialog_trigger_attrs(model: &DialogModel, controls_id: Option<&str>) -> AttrSet {
+    AttrSet::new()
+        .with(Attr::DataState(DataState::open(model.open())))
+        .with(Attr::AriaHasPopup(HasPopup::Dialog))
+        .with(Attr::AriaExpanded(model.open()))
+        .with_opt(controls_id, |controls| Attr::AriaControls(controls.into()))
+}
+
+pub fn dialog_content_attrs(
+    model: &DialogModel,
+    labelled_by: Option<&str>,
+    described_by: Option<&str>,
+) -> AttrSet {
+    AttrSet::new()
+        .with(Attr::DataState(DataState::open(model.open())))
+        .with(Attr::Role(Role::Dialog))
+        .with(Attr::TabIndex(-1))
+        .with_if(model.modal(), || Attr::AriaModal(true))
+        .with_opt(labelled_by, |label| Attr::AriaLabelledBy(label.into()))
+        .with_opt(described_by, |description| Attr::AriaDescribedBy(description.into()))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{DialogModel, DialogState};
+    use super::{DialogEvent, DialogModel, DialogState};
+    use crate::keyboard::KeyInput;
+    use crate::model::{Effect, Model};
 
     #[test]
     fn dialog_toggle_changes_state() {
@@ -65,4 +128,19 @@ mod tests {
         assert!(model.set_open(true));
         assert!(!model.set_open(true));
     }
+
+    #[test]
+    fn dialog_send_dismisses_on_escape_and_outside() {
+        let mut model = DialogModel::new(true);
+        assert!(model.send(DialogEvent::FocusOutside).is_empty());
+        assert_eq!(
+            model.send(DialogEvent::Key(KeyInput::from("Escape"))).effects(),
+            [Effect::PreventDefault, Effect::OpenChanged(false)]
+        );
+        assert!(model.send(DialogEvent::Key(KeyInput::from("Escape"))).is_empty());
+
+        model.set_modal(false);
+        model.send(DialogEvent::SetOpen(true));
+        assert_eq!(model.send(DialogEvent::FocusOutside).open_changed(), Some(false));
+    }
 }
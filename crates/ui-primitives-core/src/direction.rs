@@ -0,0 +1,60 @@
+use crate::keyboard::Key;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+impl Direction {
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.eq_ignore_ascii_case("ltr") {
+            Some(Direction::Ltr)
+        } else if value.eq_ignore_ascii_case("rtl") {
+            Some(Direction::Rtl)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_attr_value(self) -> &'static str {
+        match self {
+            Direction::Ltr => "ltr",
+            Direction::Rtl => "rtl",
+        }
+    }
+
+    pub fn is_rtl(self) -> bool {
+        self == Direction::Rtl
+    }
+
+    pub fn flip_key(self, key: Key) -> Key {
+        match (self, key) {
+            (Direction::Rtl, Key::ArrowLeft) => Key::ArrowRight,
+            (Direction::Rtl, Key::ArrowRight) => Key::ArrowLeft,
+            (_, key) => key,
+        }
+    }
+}
+
+pub trait Directional {
+    fn direction(&self) -> Direction;
+    fn set_direction(&mut self, direction: Direction);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Direction;
+    use crate::keyboard::Key;
+
+    #[test]
+    fn direction_parses_and_flips_horizontal_arrows() {
+        assert_eq!(Direction::parse(" RTL "), Some(Direction::Rtl));
+        assert_eq!(Direction::parse("auto"), None);
+        assert_eq!(Direction::Rtl.flip_key(Key::ArrowLeft), Key::ArrowRight);
+        assert_eq!(Direction::Rtl.flip_key(Key::ArrowUp), Key::ArrowUp);
+        assert_eq!(Direction::Ltr.flip_key(Key::ArrowLeft), Key::ArrowLeft);
+    }
+}
@@ -0,0 +1,313 @@
+use alloc::collections::VecDeque;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt;
+
+use crate::clock::Clock;
+use crate::controlled::{Change, ChangeSource, Controlled, Subscription};
+
+pub const HISTORY_SOURCE: ChangeSource = ChangeSource::Custom("history");
+pub const HISTORY_DEFAULT_LIMIT: usize = 100;
+
+#[derive(Clone)]
+pub struct History<T> {
+    past: VecDeque<T>,
+    present: T,
+    future: Vec<T>,
+    limit: usize,
+    coalesce_ms: u64,
+    group_start: Option<u64>,
+    clock: Option<Rc<dyn Clock>>,
+}
+
+impl<T> History<T> {
+    pub fn new(initial: T) -> Self {
+        Self {
+            past: VecDeque::new(),
+            present: initial,
+            future: Vec::new(),
+            limit: HISTORY_DEFAULT_LIMIT,
+            coalesce_ms: 0,
+            group_start: None,
+            clock: None,
+        }
+    }
+
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.set_limit(limit);
+        self
+    }
+
+    pub fn with_coalesce_window(mut self, window_ms: u64) -> Self {
+        self.coalesce_ms = window_ms;
+        self
+    }
+
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    pub fn present(&self) -> &T {
+        &self.present
+    }
+
+    pub fn past(&self) -> impl Iterator<Item = &T> {
+        self.past.iter()
+    }
+
+    pub fn future(&self) -> impl Iterator<Item = &T> {
+        self.future.iter().rev()
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn set_limit(&mut self, limit: usize) {
+        self.limit = limit;
+        while self.past.len() > limit {
+            self.past.pop_front();
+        }
+    }
+
+    pub fn coalesce_window(&self) -> u64 {
+        self.coalesce_ms
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.past.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.future.is_empty()
+    }
+
+    pub fn timeline_len(&self) -> usize {
+        self.past.len() + 1 + self.future.len()
+    }
+
+    pub fn position(&self) -> usize {
+        self.past.len()
+    }
+
+    pub fn record(&mut self, next: T) {
+        let now = self.clock.as_ref().map(|clock| clock.now());
+        self.push(next, now);
+    }
+
+    pub fn record_at(&mut self, next: T, now: u64) {
+        self.push(next, Some(now));
+    }
+
+    pub fn break_coalescing(&mut self) {
+        self.group_start = None;
+    }
+
+    pub fn undo(&mut self) -> Option<&T> {
+        let previous = self.past.pop_back()?;
+        let current = core::mem::replace(&mut self.present, previous);
+        self.future.push(current);
+        self.group_start = None;
+        Some(&self.present)
+    }
+
+    pub fn redo(&mut self) -> Option<&T> {
+        let next = self.future.pop()?;
+        let current = core::mem::replace(&mut self.present, next);
+        self.past.push_back(current);
+        self.group_start = None;
+        Some(&self.present)
+    }
+
+    pub fn jump(&mut self, delta: isize) -> Option<&T> {
+        let target = self.position().checked_add_signed(delta)?;
+        self.jump_to(target)
+    }
+
+    pub fn jump_to(&mut self, position: usize) -> Option<&T> {
+        if position >= self.timeline_len() {
+            return None;
+        }
+        while self.position() > position {
+            self.undo();
+        }
+        while self.position() < position {
+            self.redo();
+        }
+        Some(&self.present)
+    }
+
+    pub fn clear(&mut self) {
+        self.past.clear();
+        self.future.clear();
+        self.group_start = None;
+    }
+
+    fn push(&mut self, next: T, now: Option<u64>) {
+        self.future.clear();
+        let coalesce = match (now, self.group_start) {
+            (Some(now), Some(last)) => {
+                self.coalesce_ms > 0 && now.saturating_sub(last) < self.coalesce_ms
+            }
+            _ => false,
+        };
+        let previous = core::mem::replace(&mut self.present, next);
+        if !coalesce {
+            self.past.push_back(previous);
+            while self.past.len() > self.limit {
+                self.past.pop_front();
+            }
+            self.group_start = now;
+        }
+    }
+}
+
+impl<T: Clone> History<T> {
+    pub fn undo_into(&mut self, controlled: &mut Controlled<T>) -> bool {
+        let Some(value) = self.past.back().cloned() else {
+            return false;
+        };
+        if !controlled.set_with_source(value, HISTORY_SOURCE).is_committed() {
+            return false;
+        }
+        self.undo();
+        true
+    }
+
+    pub fn redo_into(&mut self, controlled: &mut Controlled<T>) -> bool {
+        let Some(value) = self.future.last().cloned() else {
+            return false;
+        };
+        if !controlled.set_with_source(value, HISTORY_SOURCE).is_committed() {
+            return false;
+        }
+        self.redo();
+        true
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for History<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("History")
+            .field("past", &self.past)
+            .field("present", &self.present)
+            .field("future", &self.future)
+            .field("limit", &self.limit)
+            .field("coalesce_ms", &self.coalesce_ms)
+            .finish_non_exhaustive()
+    }
+}
+
+pub fn history_subscribe<T: Clone + 'static>(
+    history: &Rc<RefCell<History<T>>>,
+    controlled: &mut Controlled<T>,
+) -> Subscription {
+    let history = Rc::downgrade(history);
+    controlled.subscribe(move |change: Change<'_, T>| {
+        if change.source == HISTORY_SOURCE {
+            return;
+        }
+        if let Some(history) = history.upgrade()
+            && let Ok(mut history) = history.try_borrow_mut()
+        {
+            history.record(change.next.clone());
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{history_subscribe, History, HISTORY_SOURCE};
+    use crate::clock::{Clock, ManualClock};
+    use crate::collapsible::CollapsibleModel;
+    use crate::controlled::{Change, ChangeDecision, Controlled};
+    use alloc::boxed::Box;
+    use alloc::rc::Rc;
+    use core::cell::RefCell;
+    use std::vec::Vec;
+
+    #[test]
+    fn history_undo_redo_and_jump() {
+        let mut history = History::new(0).with_limit(3);
+        for value in 1..=5 {
+            history.record(value);
+        }
+        assert_eq!(history.past().copied().collect::<Vec<_>>(), [2, 3, 4]);
+        assert_eq!(history.undo(), Some(&4));
+        assert_eq!(history.undo(), Some(&3));
+        assert_eq!(history.redo(), Some(&4));
+        assert_eq!(history.jump(-2), Some(&2));
+        assert_eq!(history.jump_to(3), Some(&5));
+        assert_eq!(history.jump(1), None);
+
+        history.jump(-1);
+        history.record(9);
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn history_coalesces_rapid_changes() {
+        let clock = ManualClock::new(0);
+        let shared: Rc<dyn Clock> = Rc::new(clock.clone());
+        let mut history = History::new(0)
+            .with_coalesce_window(300)
+            .with_clock(shared);
+        history.record(1);
+        clock.advance(100);
+        history.record(2);
+        clock.advance(100);
+        history.record(3);
+        clock.advance(500);
+        history.record(4);
+
+        assert_eq!(history.past().copied().collect::<Vec<_>>(), [0, 3]);
+        assert_eq!(history.undo(), Some(&3));
+        assert_eq!(history.undo(), Some(&0));
+
+        let mut history = History::new(0).with_coalesce_window(300);
+        for (value, now) in [(1, 0), (2, 200), (3, 400), (4, 600)] {
+            history.record_at(value, now);
+        }
+        assert_eq!(history.past().copied().collect::<Vec<_>>(), [0, 2]);
+    }
+
+    #[test]
+    fn history_tracks_controlled_models() {
+        let history = Rc::new(RefCell::new(History::new(CollapsibleModel::new(false))));
+        let mut controlled = Controlled::new(CollapsibleModel::new(false));
+        let _subscription = history_subscribe(&history, &mut controlled);
+
+        controlled.update(|model| {
+            let mut model = *model;
+            model.toggle();
+            model
+        });
+        assert!(history.borrow().present().open());
+
+        assert!(history.borrow_mut().undo_into(&mut controlled));
+        assert!(!controlled.value().open());
+        assert_eq!(history.borrow().timeline_len(), 2);
+        assert!(history.borrow().can_redo());
+    }
+
+    #[test]
+    fn history_keeps_cursor_when_controlled_rejects() {
+        let mut history = History::new(0);
+        history.record(1);
+        let mut controlled = Controlled::new(1).with_before_change(Box::new(
+            |change: Change<'_, i32>| {
+                if change.source == HISTORY_SOURCE {
+                    ChangeDecision::Reject
+                } else {
+                    ChangeDecision::Accept
+                }
+            },
+        ));
+        assert!(!history.undo_into(&mut controlled));
+        assert_eq!(history.position(), 1);
+        assert_eq!(history.present(), &1);
+        assert_eq!(controlled.value(), &1);
+    }
+}
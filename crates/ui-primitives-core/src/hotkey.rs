@@ -0,0 +1,504 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::keyboard::{Key, KeyInput, Modifiers};
+
+pub const HOTKEY_GLOBAL_SCOPE: &str = "global";
+pub const HOTKEY_SEQUENCE_TIMEOUT_MS: u64 = 1000;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HotkeyPlatform {
+    Apple,
+    #[default]
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HotkeyParseError {
+    Empty,
+    MissingKey(String),
+    UnknownModifier(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyMatcher {
+    Key(Key),
+    Code(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyChord {
+    pub matcher: KeyMatcher,
+    pub modifiers: Modifiers,
+}
+
+impl KeyChord {
+    pub fn matches(&self, input: &KeyInput) -> bool {
+        if input.is_composing {
+            return false;
+        }
+        let key_matches = match &self.matcher {
+            KeyMatcher::Code(code) => input.code.eq_ignore_ascii_case(code),
+            KeyMatcher::Key(Key::Character(expected)) => match input.key {
+                Key::Character(actual) => actual.to_lowercase().eq(expected.to_lowercase()),
+                _ => false,
+            },
+            KeyMatcher::Key(key) => &input.key == key,
+        };
+        if !key_matches {
+            return false;
+        }
+        let actual = input.modifiers;
+        let expected = self.modifiers;
+        let shift_matches = match &self.matcher {
+            KeyMatcher::Key(Key::Character(ch)) if !expected.shift && !ch.is_alphabetic() => true,
+            _ => actual.shift == expected.shift,
+        };
+        shift_matches
+            && actual.ctrl == expected.ctrl
+            && actual.alt == expected.alt
+            && actual.meta == expected.meta
+    }
+
+    pub fn label(&self, platform: HotkeyPlatform) -> String {
+        let mut label = String::new();
+        let modifiers = self.modifiers;
+        match platform {
+            HotkeyPlatform::Apple => {
+                for (active, symbol) in [
+                    (modifiers.ctrl, "⌃"),
+                    (modifiers.alt, "⌥"),
+                    (modifiers.shift, "⇧"),
+                    (modifiers.meta, "⌘"),
+                ] {
+                    if active {
+                        label.push_str(symbol);
+                    }
+                }
+            }
+            HotkeyPlatform::Other => {
+                for (active, name) in [
+                    (modifiers.ctrl, "Ctrl+"),
+                    (modifiers.alt, "Alt+"),
+                    (modifiers.shift, "Shift+"),
+                    (modifiers.meta, "Meta+"),
+                ] {
+                    if active {
+                        label.push_str(name);
+                    }
+                }
+            }
+        }
+        match &self.matcher {
+            KeyMatcher::Code(code) => label.push_str(code),
+            KeyMatcher::Key(Key::Character(ch)) => label.extend(ch.to_uppercase()),
+            KeyMatcher::Key(Key::Space) => label.push_str("Space"),
+            KeyMatcher::Key(key) => {
+                let _ = write!(label, "{key}");
+            }
+        }
+        label
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hotkey {
+    pub sequence: Vec<KeyChord>,
+}
+
+impl Hotkey {
+    pub fn parse(shortcut: &str, platform: HotkeyPlatform) -> Result<Self, HotkeyParseError> {
+        let sequence = shortcut
+            .split_whitespace()
+            .map(|step| parse_hotkey_chord(step, platform))
+            .collect::<Result<Vec<_>, _>>()?;
+        if sequence.is_empty() {
+            return Err(HotkeyParseError::Empty);
+        }
+        Ok(Self { sequence })
+    }
+
+    pub fn label(&self, platform: HotkeyPlatform) -> String {
+        let mut label = String::new();
+        for (index, chord) in self.sequence.iter().enumerate() {
+            if index > 0 {
+                label.push(' ');
+            }
+            label.push_str(&chord.label(platform));
+        }
+        label
+    }
+}
+
+fn parse_hotkey_chord(step: &str, platform: HotkeyPlatform) -> Result<KeyChord, HotkeyParseError> {
+    let (modifier_part, key_part) = if step == "+" {
+        ("", "+")
+    } else if let Some(rest) = step.strip_suffix("++") {
+        (rest, "+")
+    } else {
+        match step.rsplit_once('+') {
+            Some((modifiers, key)) => (modifiers, key),
+            None => ("", step),
+        }
+    };
+    if key_part.is_empty() {
+        return Err(HotkeyParseError::MissingKey(String::from(step)));
+    }
+
+    let mut modifiers = Modifiers::NONE;
+    for token in modifier_part.split('+').filter(|token| !token.is_empty()) {
+        let modifier = match token.to_ascii_lowercase().as_str() {
+            "mod" => match platform {
+                HotkeyPlatform::Apple => Modifiers::META,
+                HotkeyPlatform::Other => Modifiers::CTRL,
+            },
+            "ctrl" | "control" => Modifiers::CTRL,
+            "alt" | "option" | "opt" => Modifiers::ALT,
+            "shift" => Modifiers::SHIFT,
+            "meta" | "cmd" | "command" | "super" | "win" => Modifiers::META,
+            _ => return Err(HotkeyParseError::UnknownModifier(String::from(token))),
+        };
+        modifiers = modifiers.union(modifier);
+    }
+
+    let matcher = match key_part.strip_prefix("code:") {
+        Some(code) => KeyMatcher::Code(String::from(code)),
+        None => KeyMatcher::Key(parse_hotkey_key(key_part)),
+    };
+    Ok(KeyChord { matcher, modifiers })
+}
+
+fn parse_hotkey_key(name: &str) -> Key {
+    match name.to_ascii_lowercase().as_str() {
+        "space" => Key::Space,
+        "plus" => Key::Character('+'),
+        "esc" | "escape" => Key::Escape,
+        "enter" | "return" => Key::Enter,
+        "tab" => Key::Tab,
+        "up" => Key::ArrowUp,
+        "down" => Key::ArrowDown,
+        "left" => Key::ArrowLeft,
+        "right" => Key::ArrowRight,
+        "del" | "delete" => Key::Delete,
+        "backspace" => Key::Backspace,
+        "home" => Key::Home,
+        "end" => Key::End,
+        "pageup" => Key::PageUp,
+        "pagedown" => Key::PageDown,
+        _ => match Key::parse(name) {
+            Key::Character(ch) => Key::Character(ch.to_lowercase().next().unwrap_or(ch)),
+            key => key,
+        },
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HotkeyId(u64);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HotkeyBinding<A> {
+    shortcut: String,
+    action: A,
+    scope: String,
+    priority: i32,
+    description: String,
+}
+
+impl<A> HotkeyBinding<A> {
+    pub fn new(shortcut: impl Into<String>, action: A) -> Self {
+        Self {
+            shortcut: shortcut.into(),
+            action,
+            scope: String::from(HOTKEY_GLOBAL_SCOPE),
+            priority: 0,
+            description: String::new(),
+        }
+    }
+
+    pub fn with_scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = scope.into();
+        self
+    }
+
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct HotkeyEntry<A> {
+    id: HotkeyId,
+    hotkey: Hotkey,
+    binding: HotkeyBinding<A>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HotkeyHelp<'a> {
+    pub id: HotkeyId,
+    pub shortcut: String,
+    pub description: &'a str,
+    pub scope: &'a str,
+    pub active: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HotkeyRegistry<A> {
+    platform: HotkeyPlatform,
+    entries: Vec<HotkeyEntry<A>>,
+    scopes: Vec<String>,
+    pending: Vec<KeyInput>,
+    last_input: Option<u64>,
+    sequence_timeout: u64,
+    next_id: u64,
+}
+
+impl<A> HotkeyRegistry<A> {
+    pub fn new(platform: HotkeyPlatform) -> Self {
+        Self {
+            platform,
+            entries: Vec::new(),
+            scopes: Vec::new(),
+            pending: Vec::new(),
+            last_input: None,
+            sequence_timeout: HOTKEY_SEQUENCE_TIMEOUT_MS,
+            next_id: 0,
+        }
+    }
+
+    pub fn with_sequence_timeout(mut self, timeout_ms: u64) -> Self {
+        self.sequence_timeout = timeout_ms;
+        self
+    }
+
+    pub fn platform(&self) -> HotkeyPlatform {
+        self.platform
+    }
+
+    pub fn register(&mut self, binding: HotkeyBinding<A>) -> Result<HotkeyId, HotkeyParseError> {
+        let hotkey = Hotkey::parse(&binding.shortcut, self.platform)?;
+        let id = HotkeyId(self.next_id);
+        self.next_id += 1;
+        self.entries.push(HotkeyEntry {
+            id,
+            hotkey,
+            binding,
+        });
+        Ok(id)
+    }
+
+    pub fn unregister(&mut self, id: HotkeyId) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|entry| entry.id != id);
+        self.entries.len() != before
+    }
+
+    pub fn push_scope(&mut self, scope: impl Into<String>) {
+        self.scopes.push(scope.into());
+        self.pending.clear();
+    }
+
+    pub fn pop_scope(&mut self, scope: &str) -> bool {
+        let Some(index) = self.scopes.iter().rposition(|active| active == scope) else {
+            return false;
+        };
+        self.scopes.remove(index);
+        self.pending.clear();
+        true
+    }
+
+    pub fn active_scopes(&self) -> impl Iterator<Item = &str> {
+        core::iter::once(HOTKEY_GLOBAL_SCOPE).chain(self.scopes.iter().map(String::as_str))
+    }
+
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    pub fn reset(&mut self) {
+        self.pending.clear();
+        self.last_input = None;
+    }
+
+    pub fn help(&self) -> Vec<HotkeyHelp<'_>> {
+        self.entries
+            .iter()
+            .map(|entry| HotkeyHelp {
+                id: entry.id,
+                shortcut: entry.hotkey.label(self.platform),
+                description: &entry.binding.description,
+                scope: &entry.binding.scope,
+                active: self.scope_rank(&entry.binding.scope).is_some(),
+            })
+            .collect()
+    }
+
+    pub fn handle(&mut self, input: &KeyInput, now: u64) -> Option<&A> {
+        if input.key.is_modifier() || input.is_composing {
+            return None;
+        }
+        if self
+            .last_input
+            .is_some_and(|last| now.saturating_sub(last) > self.sequence_timeout)
+        {
+            self.pending.clear();
+        }
+        self.last_input = Some(now);
+
+        let resumed = !self.pending.is_empty();
+        self.pending.push(input.clone());
+        if let Some(found) = self.resolve() {
+            return found.map(|index| &self.entries[index].binding.action);
+        }
+        if resumed {
+            self.pending.clear();
+            self.pending.push(input.clone());
+            if let Some(found) = self.resolve() {
+                return found.map(|index| &self.entries[index].binding.action);
+            }
+        }
+        self.pending.clear();
+        None
+    }
+
+    fn resolve(&mut self) -> Option<Option<usize>> {
+        let mut best: Option<(usize, (usize, i32))> = None;
+        let mut partial = false;
+        for (index, entry) in self.entries.iter().enumerate() {
+            let Some(rank) = self.scope_rank(&entry.binding.scope) else {
+                continue;
+            };
+            let sequence = &entry.hotkey.sequence;
+            if sequence.len() < self.pending.len() {
+                continue;
+            }
+            let prefix = sequence
+                .iter()
+                .zip(&self.pending)
+                .all(|(chord, input)| chord.matches(input));
+            if !prefix {
+                continue;
+            }
+            if sequence.len() > self.pending.len() {
+                partial = true;
+                continue;
+            }
+            let key = (rank, entry.binding.priority);
+            if best.is_none_or(|(_, current)| key > current) {
+                best = Some((index, key));
+            }
+        }
+        if let Some((index, _)) = best {
+            self.pending.clear();
+            return Some(Some(index));
+        }
+        partial.then_some(None)
+    }
+
+    fn scope_rank(&self, scope: &str) -> Option<usize> {
+        if scope == HOTKEY_GLOBAL_SCOPE {
+            return Some(0);
+        }
+        self.scopes
+            .iter()
+            .rposition(|active| active == scope)
+            .map(|index| index + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Hotkey,
+        HotkeyBinding,
+        HotkeyParseError,
+        HotkeyPlatform,
+        HotkeyRegistry,
+    };
+    use crate::keyboard::{KeyInput, Modifiers};
+
+    fn press(key: &str, modifiers: Modifiers) -> KeyInput {
+        KeyInput::from(key).with_modifiers(modifiers)
+    }
+
+    #[test]
+    fn hotkey_parses_mod_per_platform() {
+        let apple = Hotkey::parse("Mod+Shift+K", HotkeyPlatform::Apple).expect("hotkey");
+        assert!(apple.sequence[0].matches(&press("K", Modifiers::META.union(Modifiers::SHIFT))));
+        assert_eq!(apple.label(HotkeyPlatform::Apple), "⇧⌘K");
+
+        let other = Hotkey::parse("Mod+Shift+K", HotkeyPlatform::Other).expect("hotkey");
+        assert!(!other.sequence[0].matches(&press("K", Modifiers::META.union(Modifiers::SHIFT))));
+        assert_eq!(other.label(HotkeyPlatform::Other), "Ctrl+Shift+K");
+
+        let code = Hotkey::parse("Alt+code:KeyQ", HotkeyPlatform::Other).expect("hotkey");
+        assert!(code.sequence[0].matches(&KeyInput::parse("œ", "KeyQ").with_modifiers(Modifiers::ALT)));
+
+        assert_eq!(
+            Hotkey::parse("Hyper+K", HotkeyPlatform::Other),
+            Err(HotkeyParseError::UnknownModifier("Hyper".into()))
+        );
+        assert_eq!(Hotkey::parse("  ", HotkeyPlatform::Other), Err(HotkeyParseError::Empty));
+    }
+
+    #[test]
+    fn registry_matches_sequences_with_timeout() {
+        let mut registry = HotkeyRegistry::new(HotkeyPlatform::Other);
+        registry
+            .register(HotkeyBinding::new("g i", "inbox").with_description("Go to inbox"))
+            .expect("register");
+        registry.register(HotkeyBinding::new("i", "info")).expect("register");
+
+        assert_eq!(registry.handle(&press("g", Modifiers::NONE), 0), None);
+        assert!(registry.is_pending());
+        assert_eq!(registry.handle(&press("i", Modifiers::NONE), 200), Some(&"inbox"));
+
+        assert_eq!(registry.handle(&press("g", Modifiers::NONE), 1_000), None);
+        assert_eq!(registry.handle(&press("i", Modifiers::NONE), 5_000), Some(&"info"));
+    }
+
+    #[test]
+    fn registry_scopes_shadow_global_bindings() {
+        let mut registry = HotkeyRegistry::new(HotkeyPlatform::Other);
+        registry
+            .register(HotkeyBinding::new("Escape", "clear-search").with_priority(5))
+            .expect("register");
+        registry
+            .register(
+                HotkeyBinding::new("Esc", "close-dialog")
+                    .with_scope("dialog")
+                    .with_description("Close dialog"),
+            )
+            .expect("register");
+
+        assert_eq!(registry.handle(&press("Escape", Modifiers::NONE), 0), Some(&"clear-search"));
+        registry.push_scope("dialog");
+        assert_eq!(registry.handle(&press("Escape", Modifiers::NONE), 0), Some(&"close-dialog"));
+
+        let help = registry.help();
+        assert_eq!(help[1].shortcut, "Escape");
+        assert_eq!(help[1].description, "Close dialog");
+        assert!(help[1].active);
+        assert!(registry.pop_scope("dialog"));
+        assert!(!registry.help()[1].active);
+    }
+
+    #[test]
+    fn registry_keeps_shift_distinct_for_letters() {
+        let mut registry = HotkeyRegistry::new(HotkeyPlatform::Other);
+        registry.register(HotkeyBinding::new("Mod+K", "search")).expect("register");
+        registry.register(HotkeyBinding::new("Mod+Shift+K", "palette")).expect("register");
+        registry.register(HotkeyBinding::new("?", "help")).expect("register");
+
+        assert_eq!(registry.handle(&press("k", Modifiers::CTRL), 0), Some(&"search"));
+        let shifted = Modifiers::CTRL.union(Modifiers::SHIFT);
+        assert_eq!(registry.handle(&press("K", shifted), 0), Some(&"palette"));
+        assert_eq!(registry.handle(&press("?", Modifiers::SHIFT), 0), Some(&"help"));
+    }
+}
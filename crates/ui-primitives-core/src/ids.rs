@@ -1,5 +1,6 @@
 use alloc::string::String;
 use alloc::vec::Vec;
+use core::fmt::Write;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct IdGenerator {
@@ -27,6 +28,12 @@ impl IdGenerator {
         self.counter = self.counter.saturating_add(1);
         alloc::format!("{}-{}", self.prefix, self.counter)
     }
+
+    pub fn next_id_in(&mut self, registry: &mut IdRegistry) -> Result<String, IdCollision> {
+        let id = self.next_id();
+        registry.claim(id.clone())?;
+        Ok(id)
+    }
 }
 
 pub fn generate_ids(prefix: impl Into<String>, count: usize) -> Vec<String> {
@@ -38,9 +45,160 @@ pub fn generate_ids(prefix: impl Into<String>, count: usize) -> Vec<String> {
     ids
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdScope {
+    prefix: String,
+    path: Vec<u32>,
+    children: u32,
+    locals: u32,
+}
+
+impl IdScope {
+    pub fn root(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            path: Vec::new(),
+            children: 0,
+            locals: 0,
+        }
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn path(&self) -> &[u32] {
+        &self.path
+    }
+
+    pub fn depth(&self) -> usize {
+        self.path.len()
+    }
+
+    pub fn child(&mut self) -> IdScope {
+        self.children = self.children.saturating_add(1);
+        self.child_at(self.children)
+    }
+
+    pub fn child_at(&self, index: u32) -> IdScope {
+        let mut path = self.path.clone();
+        path.push(index);
+        Self {
+            prefix: self.prefix.clone(),
+            path,
+            children: 0,
+            locals: 0,
+        }
+    }
+
+    pub fn next_id(&mut self) -> String {
+        self.locals = self.locals.saturating_add(1);
+        self.id_at(self.locals)
+    }
+
+    pub fn next_id_in(&mut self, registry: &mut IdRegistry) -> Result<String, IdCollision> {
+        let id = self.next_id();
+        registry.claim(id.clone())?;
+        Ok(id)
+    }
+
+    pub fn id_at(&self, local: u32) -> String {
+        let mut id = self.prefix.clone();
+        for segment in &self.path {
+            let _ = write!(id, "-{segment}");
+        }
+        let _ = write!(id, ":{local}");
+        id
+    }
+
+    pub fn reset(&mut self) {
+        self.children = 0;
+        self.locals = 0;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdCollision {
+    DuplicatePrefix(String),
+    OverlappingPrefix { existing: String, prefix: String },
+    DuplicateId(String),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IdRegistry {
+    prefixes: Vec<String>,
+    ids: Vec<String>,
+}
+
+impl IdRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn prefixes(&self) -> &[String] {
+        &self.prefixes
+    }
+
+    pub fn register_prefix(&mut self, prefix: impl Into<String>) -> Result<(), IdCollision> {
+        let prefix = prefix.into();
+        for existing in &self.prefixes {
+            if *existing == prefix {
+                return Err(IdCollision::DuplicatePrefix(prefix));
+            }
+            if id_prefix_overlaps(existing, &prefix) || id_prefix_overlaps(&prefix, existing) {
+                return Err(IdCollision::OverlappingPrefix {
+                    existing: existing.clone(),
+                    prefix,
+                });
+            }
+        }
+        self.prefixes.push(prefix);
+        Ok(())
+    }
+
+    pub fn scope(&mut self, prefix: impl Into<String>) -> Result<IdScope, IdCollision> {
+        let prefix = prefix.into();
+        self.register_prefix(prefix.clone())?;
+        Ok(IdScope::root(prefix))
+    }
+
+    pub fn generator(&mut self, prefix: impl Into<String>) -> Result<IdGenerator, IdCollision> {
+        let prefix = prefix.into();
+        self.register_prefix(prefix.clone())?;
+        Ok(IdGenerator::new(prefix))
+    }
+
+    pub fn claim(&mut self, id: impl Into<String>) -> Result<(), IdCollision> {
+        let id = id.into();
+        if self.ids.contains(&id) {
+            return Err(IdCollision::DuplicateId(id));
+        }
+        self.ids.push(id);
+        Ok(())
+    }
+
+    pub fn release(&mut self, id: &str) -> bool {
+        let before = self.ids.len();
+        self.ids.retain(|claimed| claimed != id);
+        self.ids.len() != before
+    }
+}
+
+fn id_prefix_overlaps(shorter: &str, longer: &str) -> bool {
+    longer
+        .strip_prefix(shorter)
+        .is_some_and(|rest| rest.starts_with(['-', ':']))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{generate_ids, IdGenerator};
+    use super::{
+        generate_ids,
+        IdCollision,
+        IdGenerator,
+        IdRegistry,
+        IdScope,
+    };
 
     #[test]
     fn id_generator_increments() {
@@ -54,4 +212,59 @@ mod tests {
         let ids = generate_ids("item", 3);
         assert_eq!(ids.as_slice(), &["item-1", "item-2", "item-3"]);
     }
+
+    #[test]
+    fn id_scopes_are_path_based() {
+        let mut root = IdScope::root("ui");
+        let mut dialog = root.child();
+        let mut tabs = root.child();
+        assert_eq!(dialog.next_id(), "ui-1:1");
+        assert_eq!(tabs.next_id(), "ui-2:1");
+        assert_eq!(tabs.child().next_id(), "ui-2-1:1");
+        assert_eq!(root.child_at(2).id_at(1), "ui-2:1");
+        assert_eq!(dialog.next_id(), "ui-1:2");
+    }
+
+    #[test]
+    fn id_registry_detects_collisions() {
+        let mut registry = IdRegistry::new();
+        assert!(registry.scope("ui").is_ok());
+        assert_eq!(
+            registry.register_prefix("ui"),
+            Err(IdCollision::DuplicatePrefix("ui".into()))
+        );
+        assert_eq!(
+            registry.register_prefix("ui-2"),
+            Err(IdCollision::OverlappingPrefix {
+                existing: "ui".into(),
+                prefix: "ui-2".into(),
+            })
+        );
+        assert!(registry.register_prefix("uikit").is_ok());
+        assert!(registry.claim("ui-1:1").is_ok());
+        assert_eq!(
+            registry.claim("ui-1:1"),
+            Err(IdCollision::DuplicateId("ui-1:1".into()))
+        );
+    }
+
+    #[test]
+    fn id_generators_claim_through_registry() {
+        let mut registry = IdRegistry::new();
+        let mut tabs = registry.generator("tab").expect("fresh prefix");
+        assert!(registry.generator("tab").is_err());
+        assert_eq!(tabs.next_id_in(&mut registry), Ok("tab-1".into()));
+
+        let mut stale = IdGenerator::new("tab");
+        assert_eq!(
+            stale.next_id_in(&mut registry),
+            Err(IdCollision::DuplicateId("tab-1".into()))
+        );
+        assert_eq!(stale.next_id_in(&mut registry), Ok("tab-2".into()));
+
+        let mut scope = registry.scope("ui").expect("fresh prefix");
+        assert_eq!(scope.next_id_in(&mut registry), Ok("ui:1".into()));
+        scope.reset();
+        assert!(scope.next_id_in(&mut registry).is_err());
+    }
 }
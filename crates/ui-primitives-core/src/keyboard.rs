@@ -0,0 +1,282 @@
+use alloc::string::String;
+use core::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Key {
+    Character(char),
+    Space,
+    Enter,
+    Escape,
+    Tab,
+    Backspace,
+    Delete,
+    Insert,
+    ArrowUp,
+    ArrowDown,
+    ArrowLeft,
+    ArrowRight,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Shift,
+    Control,
+    Alt,
+    Meta,
+    CapsLock,
+    ContextMenu,
+    Function(u8),
+    Dead,
+    Process,
+    Unidentified,
+    Other(String),
+}
+
+impl Key {
+    pub fn parse(key: &str) -> Self {
+        match key {
+            " " | "Spacebar" => Self::Space,
+            "Enter" => Self::Enter,
+            "Escape" | "Esc" => Self::Escape,
+            "Tab" => Self::Tab,
+            "Backspace" => Self::Backspace,
+            "Delete" | "Del" => Self::Delete,
+            "Insert" => Self::Insert,
+            "ArrowUp" | "Up" => Self::ArrowUp,
+            "ArrowDown" | "Down" => Self::ArrowDown,
+            "ArrowLeft" | "Left" => Self::ArrowLeft,
+            "ArrowRight" | "Right" => Self::ArrowRight,
+            "Home" => Self::Home,
+            "End" => Self::End,
+            "PageUp" => Self::PageUp,
+            "PageDown" => Self::PageDown,
+            "Shift" => Self::Shift,
+            "Control" => Self::Control,
+            "Alt" | "AltGraph" => Self::Alt,
+            "Meta" | "OS" => Self::Meta,
+            "CapsLock" => Self::CapsLock,
+            "ContextMenu" | "Apps" => Self::ContextMenu,
+            "Dead" => Self::Dead,
+            "Process" => Self::Process,
+            "" | "Unidentified" => Self::Unidentified,
+            _ => {
+                let mut chars = key.chars();
+                if let (Some(ch), None) = (chars.next(), chars.next())
+                    && !ch.is_control()
+                {
+                    return Self::Character(ch);
+                }
+                match key
+                    .strip_prefix('F')
+                    .and_then(|number| number.parse::<u8>().ok())
+                {
+                    Some(number @ 1..=24) => Self::Function(number),
+                    _ => Self::Other(String::from(key)),
+                }
+            }
+        }
+    }
+
+    pub fn is_modifier(&self) -> bool {
+        matches!(self, Self::Shift | Self::Control | Self::Alt | Self::Meta | Self::CapsLock)
+    }
+
+    pub fn is_arrow(&self) -> bool {
+        matches!(
+            self,
+            Self::ArrowUp | Self::ArrowDown | Self::ArrowLeft | Self::ArrowRight
+        )
+    }
+
+    pub fn char(&self) -> Option<char> {
+        match self {
+            Self::Character(ch) => Some(*ch),
+            Self::Space => Some(' '),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Character(ch) => return write!(f, "{ch}"),
+            Self::Function(number) => return write!(f, "F{number}"),
+            Self::Other(name) => return f.write_str(name),
+            Self::Space => " ",
+            Self::Enter => "Enter",
+            Self::Escape => "Escape",
+            Self::Tab => "Tab",
+            Self::Backspace => "Backspace",
+            Self::Delete => "Delete",
+            Self::Insert => "Insert",
+            Self::ArrowUp => "ArrowUp",
+            Self::ArrowDown => "ArrowDown",
+            Self::ArrowLeft => "ArrowLeft",
+            Self::ArrowRight => "ArrowRight",
+            Self::Home => "Home",
+            Self::End => "End",
+            Self::PageUp => "PageUp",
+            Self::PageDown => "PageDown",
+            Self::Shift => "Shift",
+            Self::Control => "Control",
+            Self::Alt => "Alt",
+            Self::Meta => "Meta",
+            Self::CapsLock => "CapsLock",
+            Self::ContextMenu => "ContextMenu",
+            Self::Dead => "Dead",
+            Self::Process => "Process",
+            Self::Unidentified => "Unidentified",
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub meta: bool,
+}
+
+impl Modifiers {
+    pub const NONE: Self = Self {
+        shift: false,
+        ctrl: false,
+        alt: false,
+        meta: false,
+    };
+    pub const SHIFT: Self = Self {
+        shift: true,
+        ..Self::NONE
+    };
+    pub const CTRL: Self = Self {
+        ctrl: true,
+        ..Self::NONE
+    };
+    pub const ALT: Self = Self {
+        alt: true,
+        ..Self::NONE
+    };
+    pub const META: Self = Self {
+        meta: true,
+        ..Self::NONE
+    };
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::NONE
+    }
+
+    pub fn has_command(&self) -> bool {
+        self.ctrl || self.alt || self.meta
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            shift: self.shift || other.shift,
+            ctrl: self.ctrl || other.ctrl,
+            alt: self.alt || other.alt,
+            meta: self.meta || other.meta,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyInput {
+    pub key: Key,
+    pub code: String,
+    pub modifiers: Modifiers,
+    pub repeat: bool,
+    pub is_composing: bool,
+}
+
+impl KeyInput {
+    pub fn new(key: Key) -> Self {
+        Self {
+            key,
+            code: String::new(),
+            modifiers: Modifiers::NONE,
+            repeat: false,
+            is_composing: false,
+        }
+    }
+
+    pub fn parse(key: &str, code: &str) -> Self {
+        Self::new(Key::parse(key)).with_code(code)
+    }
+
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = code.into();
+        self
+    }
+
+    pub fn with_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
+    pub fn with_repeat(mut self, repeat: bool) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    pub fn with_composing(mut self, is_composing: bool) -> Self {
+        self.is_composing = is_composing;
+        self
+    }
+
+    pub fn is(&self, key: &Key) -> bool {
+        &self.key == key && !self.is_composing
+    }
+
+    pub fn is_plain(&self, key: &Key) -> bool {
+        self.is(key) && !self.modifiers.has_command()
+    }
+}
+
+impl From<Key> for KeyInput {
+    fn from(key: Key) -> Self {
+        Self::new(key)
+    }
+}
+
+impl From<&str> for KeyInput {
+    fn from(key: &str) -> Self {
+        Self::new(Key::parse(key))
+    }
+}
+
+impl From<&KeyInput> for KeyInput {
+    fn from(input: &KeyInput) -> Self {
+        input.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Key, KeyInput, Modifiers};
+    use alloc::string::ToString;
+
+    #[test]
+    fn key_parses_dom_names() {
+        assert_eq!(Key::parse("ArrowLeft"), Key::ArrowLeft);
+        assert_eq!(Key::parse("Esc"), Key::Escape);
+        assert_eq!(Key::parse(" "), Key::Space);
+        assert_eq!(Key::parse("k"), Key::Character('k'));
+        assert_eq!(Key::parse("F12"), Key::Function(12));
+        assert_eq!(Key::parse("MediaPlayPause"), Key::Other("MediaPlayPause".into()));
+        assert_eq!(Key::PageDown.to_string(), "PageDown");
+    }
+
+    #[test]
+    fn key_input_tracks_modifiers_and_composition() {
+        let input = KeyInput::parse("Home", "Home").with_modifiers(Modifiers::CTRL);
+        assert!(input.is(&Key::Home));
+        assert!(!input.is_plain(&Key::Home));
+        assert_eq!(input.code, "Home");
+
+        let composing = KeyInput::from("Enter").with_composing(true);
+        assert!(!composing.is(&Key::Enter));
+    }
+}
@@ -3,12 +3,25 @@
 
 extern crate alloc;
 
+pub mod aria;
+pub mod clock;
 pub mod collapsible;
+pub mod collection;
+pub mod color;
+pub mod command;
 pub mod controlled;
 pub mod dialog;
+pub mod direction;
+pub mod history;
+pub mod hotkey;
 pub mod ids;
+pub mod keyboard;
+pub mod model;
 pub mod orientation;
 pub mod roving_focus;
+pub mod selection;
+pub mod sheet;
+pub mod slider;
 pub mod state_machine;
 pub mod tabs;
 pub mod typeahead;
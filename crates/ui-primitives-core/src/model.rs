@@ -0,0 +1,104 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Effect<K = ()> {
+    FocusItem(K),
+    PreventDefault,
+    Announce(String),
+    OpenChanged(bool),
+    SelectionChanged(Option<K>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome<K = ()> {
+    effects: Vec<Effect<K>>,
+}
+
+impl<K> Default for Outcome<K> {
+    fn default() -> Self {
+        Self {
+            effects: Vec::new(),
+        }
+    }
+}
+
+impl<K> Outcome<K> {
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, effect: Effect<K>) -> Self {
+        self.push(effect);
+        self
+    }
+
+    pub fn push(&mut self, effect: Effect<K>) {
+        self.effects.push(effect);
+    }
+
+    pub fn effects(&self) -> &[Effect<K>] {
+        &self.effects
+    }
+
+    pub fn into_effects(self) -> Vec<Effect<K>> {
+        self.effects
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    pub fn prevents_default(&self) -> bool {
+        self.effects
+            .iter()
+            .any(|effect| matches!(effect, Effect::PreventDefault))
+    }
+
+    pub fn focus_target(&self) -> Option<&K> {
+        self.effects.iter().rev().find_map(|effect| match effect {
+            Effect::FocusItem(key) => Some(key),
+            _ => None,
+        })
+    }
+
+    pub fn open_changed(&self) -> Option<bool> {
+        self.effects.iter().rev().find_map(|effect| match effect {
+            Effect::OpenChanged(open) => Some(*open),
+            _ => None,
+        })
+    }
+}
+
+impl<K> IntoIterator for Outcome<K> {
+    type Item = Effect<K>;
+    type IntoIter = alloc::vec::IntoIter<Effect<K>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.effects.into_iter()
+    }
+}
+
+pub trait Model {
+    type Event;
+    type Key;
+
+    fn send(&mut self, event: Self::Event) -> Outcome<Self::Key>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Effect, Outcome};
+
+    #[test]
+    fn outcome_reports_effects() {
+        let outcome = Outcome::none()
+            .with(Effect::FocusItem(1))
+            .with(Effect::PreventDefault)
+            .with(Effect::FocusItem(2));
+        assert!(outcome.prevents_default());
+        assert_eq!(outcome.focus_target(), Some(&2));
+        assert_eq!(outcome.open_changed(), None);
+        assert_eq!(outcome.into_effects().len(), 3);
+    }
+}
@@ -1,5 +1,6 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Orientation {
+    #[default]
     Horizontal,
     Vertical,
 }
@@ -11,21 +12,67 @@ impl Orientation {
             Orientation::Vertical => "vertical",
         }
     }
+
+    pub fn is_horizontal(self) -> bool {
+        self == Orientation::Horizontal
+    }
+
+    pub fn is_vertical(self) -> bool {
+        self == Orientation::Vertical
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RovingFocusOrientation {
+    Horizontal,
+    Vertical,
+    Both,
 }
 
-impl Default for Orientation {
-    fn default() -> Self {
-        Orientation::Horizontal
+impl RovingFocusOrientation {
+    pub fn includes(self, orientation: Orientation) -> bool {
+        match self {
+            RovingFocusOrientation::Horizontal => orientation.is_horizontal(),
+            RovingFocusOrientation::Vertical => orientation.is_vertical(),
+            RovingFocusOrientation::Both => true,
+        }
+    }
+
+    pub fn as_orientation(self) -> Option<Orientation> {
+        match self {
+            RovingFocusOrientation::Horizontal => Some(Orientation::Horizontal),
+            RovingFocusOrientation::Vertical => Some(Orientation::Vertical),
+            RovingFocusOrientation::Both => None,
+        }
+    }
+}
+
+impl From<Orientation> for RovingFocusOrientation {
+    fn from(orientation: Orientation) -> Self {
+        match orientation {
+            Orientation::Horizontal => RovingFocusOrientation::Horizontal,
+            Orientation::Vertical => RovingFocusOrientation::Vertical,
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Orientation;
+    use super::{Orientation, RovingFocusOrientation};
 
     #[test]
     fn orientation_aria_value() {
         assert_eq!(Orientation::Horizontal.as_aria_value(), "horizontal");
         assert_eq!(Orientation::Vertical.as_aria_value(), "vertical");
     }
+
+    #[test]
+    fn orientation_converts_to_roving_focus_orientation() {
+        let roving = RovingFocusOrientation::from(Orientation::Vertical);
+        assert_eq!(roving, RovingFocusOrientation::Vertical);
+        assert_eq!(roving.as_orientation(), Some(Orientation::Vertical));
+        assert_eq!(RovingFocusOrientation::Both.as_orientation(), None);
+        assert!(RovingFocusOrientation::Both.includes(Orientation::Horizontal));
+        assert!(!roving.includes(Orientation::Horizontal));
+    }
 }
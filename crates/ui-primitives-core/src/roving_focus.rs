@@ -1,56 +1,76 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct RovingFocus {
-    len: usize,
-    active: Option<usize>,
+use crate::collection::{collection_resolve_key_by, Collection};
+use crate::direction::Direction;
+use crate::keyboard::{Key, KeyInput};
+use crate::orientation::Orientation;
+pub use crate::orientation::RovingFocusOrientation;
+use crate::typeahead::{typeahead_key_char, Typeahead};
+
+pub const ROVING_FOCUS_PAGE_SIZE: usize = 10;
+
+#[derive(Debug, Clone)]
+pub struct RovingFocus<K = usize> {
+    collection: Collection<K>,
+    active: Option<K>,
     looped: bool,
+    focusable_when_disabled: bool,
+    page_size: usize,
+    typeahead: Typeahead,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum RovingFocusOrientation {
-    Horizontal,
-    Vertical,
-    Both,
+impl<K: PartialEq> PartialEq for RovingFocus<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.collection == other.collection
+            && self.active == other.active
+            && self.looped == other.looped
+            && self.focusable_when_disabled == other.focusable_when_disabled
+            && self.page_size == other.page_size
+            && self.typeahead.timeout() == other.typeahead.timeout()
+            && self.typeahead.options() == other.typeahead.options()
+    }
 }
 
+impl<K: Eq> Eq for RovingFocus<K> {}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RovingFocusAction {
     Next,
     Prev,
     First,
     Last,
+    PageNext,
+    PagePrev,
+    Typeahead(char),
 }
 
 pub fn roving_focus_action_from_key(
-    key: &str,
-    orientation: RovingFocusOrientation,
+    input: impl Into<KeyInput>,
+    orientation: impl Into<RovingFocusOrientation>,
+    direction: Direction,
 ) -> Option<RovingFocusAction> {
-    match key {
-        "Home" => Some(RovingFocusAction::First),
-        "End" => Some(RovingFocusAction::Last),
-        "ArrowLeft" => matches!(
-            orientation,
-            RovingFocusOrientation::Horizontal | RovingFocusOrientation::Both
-        )
-        .then_some(RovingFocusAction::Prev),
-        "ArrowRight" => matches!(
-            orientation,
-            RovingFocusOrientation::Horizontal | RovingFocusOrientation::Both
-        )
-        .then_some(RovingFocusAction::Next),
-        "ArrowUp" => matches!(
-            orientation,
-            RovingFocusOrientation::Vertical | RovingFocusOrientation::Both
-        )
-        .then_some(RovingFocusAction::Prev),
-        "ArrowDown" => matches!(
-            orientation,
-            RovingFocusOrientation::Vertical | RovingFocusOrientation::Both
-        )
-        .then_some(RovingFocusAction::Next),
+    let input = input.into();
+    if input.is_composing || input.modifiers.has_command() {
+        return None;
+    }
+    let orientation = orientation.into();
+    let horizontal = orientation.includes(Orientation::Horizontal);
+    let vertical = orientation.includes(Orientation::Vertical);
+    match direction.flip_key(input.key) {
+        Key::Home => Some(RovingFocusAction::First),
+        Key::End => Some(RovingFocusAction::Last),
+        Key::PageUp => Some(RovingFocusAction::PagePrev),
+        Key::PageDown => Some(RovingFocusAction::PageNext),
+        Key::ArrowLeft => horizontal.then_some(RovingFocusAction::Prev),
+        Key::ArrowRight => horizontal.then_some(RovingFocusAction::Next),
+        Key::ArrowUp => vertical.then_some(RovingFocusAction::Prev),
+        Key::ArrowDown => vertical.then_some(RovingFocusAction::Next),
         _ => None,
     }
 }
 
+pub fn roving_focus_typeahead_from_key(input: impl Into<KeyInput>) -> Option<RovingFocusAction> {
+    typeahead_key_char(input).map(RovingFocusAction::Typeahead)
+}
+
 pub fn roving_focus_next_index(
     current: usize,
     count: usize,
@@ -63,10 +83,8 @@ pub fn roving_focus_next_index(
 
     let mut focus = RovingFocus::with_active(count, Some(current), looped);
     match action {
-        RovingFocusAction::First => focus.move_first().unwrap_or(0),
-        RovingFocusAction::Last => focus.move_last().unwrap_or(0),
-        RovingFocusAction::Next => focus.move_next().unwrap_or(current),
-        RovingFocusAction::Prev => focus.move_prev().unwrap_or(current),
+        RovingFocusAction::First | RovingFocusAction::Last => focus.apply(action, 0).unwrap_or(0),
+        _ => focus.apply(action, 0).unwrap_or(current),
     }
 }
 
@@ -76,21 +94,67 @@ impl RovingFocus {
     }
 
     pub fn with_active(len: usize, active: Option<usize>, looped: bool) -> Self {
+        let mut focus = Self::from_collection(Collection::indexed(len), looped);
+        focus.set_active(active);
+        focus
+    }
+
+    pub fn set_len(&mut self, len: usize) -> Option<usize> {
+        self.set_collection(Collection::indexed(len));
+        self.active()
+    }
+}
+
+impl<K: PartialEq + Clone> RovingFocus<K> {
+    pub fn from_collection(collection: Collection<K>, looped: bool) -> Self {
         let mut focus = Self {
-            len,
-            active,
+            collection,
+            active: None,
             looped,
+            focusable_when_disabled: false,
+            page_size: ROVING_FOCUS_PAGE_SIZE,
+            typeahead: Typeahead::new(),
         };
-        focus.active = focus.clamp_active(focus.active);
+        focus.active = focus
+            .first_focusable()
+            .and_then(|index| focus.collection.key_at(index).cloned());
         focus
     }
 
+    pub fn with_focusable_when_disabled(mut self, focusable_when_disabled: bool) -> Self {
+        self.focusable_when_disabled = focusable_when_disabled;
+        self
+    }
+
+    pub fn collection(&self) -> &Collection<K> {
+        &self.collection
+    }
+
+    pub fn set_collection(&mut self, collection: Collection<K>) {
+        let focusable_when_disabled = self.focusable_when_disabled;
+        self.active = collection_resolve_key_by(
+            &self.collection,
+            &collection,
+            self.active.as_ref(),
+            |item| focusable_when_disabled || !item.disabled(),
+        );
+        self.collection = collection;
+    }
+
     pub fn len(&self) -> usize {
-        self.len
+        self.collection.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.collection.is_empty()
     }
 
     pub fn active(&self) -> Option<usize> {
-        self.active
+        self.collection.index_of(self.active.as_ref()?)
+    }
+
+    pub fn active_key(&self) -> Option<&K> {
+        self.active.as_ref()
     }
 
     pub fn looped(&self) -> bool {
@@ -101,86 +165,166 @@ impl RovingFocus {
         self.looped = looped;
     }
 
-    pub fn set_len(&mut self, len: usize) -> Option<usize> {
-        self.len = len;
-        self.active = self.clamp_active(self.active);
-        self.active
+    pub fn focusable_when_disabled(&self) -> bool {
+        self.focusable_when_disabled
+    }
+
+    pub fn set_focusable_when_disabled(&mut self, focusable_when_disabled: bool) {
+        self.focusable_when_disabled = focusable_when_disabled;
+    }
+
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.set_page_size(page_size);
+        self
+    }
+
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    pub fn set_page_size(&mut self, page_size: usize) {
+        self.page_size = page_size.max(1);
+    }
+
+    pub fn typeahead(&self) -> &Typeahead {
+        &self.typeahead
+    }
+
+    pub fn set_disabled(&mut self, key: &K, disabled: bool) -> bool {
+        self.collection.set_disabled(key, disabled)
+    }
+
+    pub fn is_focusable_at(&self, index: usize) -> bool {
+        index < self.len() && (self.focusable_when_disabled || !self.collection.is_disabled_at(index))
     }
 
     pub fn set_active(&mut self, index: Option<usize>) -> Option<usize> {
-        self.active = self.clamp_active(index);
-        self.active
+        match self.clamp_active(index) {
+            Some(index) if self.is_focusable_at(index) => {
+                self.active = self.collection.key_at(index).cloned();
+            }
+            Some(_) => {}
+            None => self.active = None,
+        }
+        self.active()
     }
 
-    pub fn move_next(&mut self) -> Option<usize> {
-        let len = self.len;
-        if len == 0 {
-            self.active = None;
-            return None;
+    pub fn set_active_key(&mut self, key: Option<&K>) -> Option<&K> {
+        match key.and_then(|key| self.collection.index_of(key)) {
+            Some(index) if self.is_focusable_at(index) => {
+                self.active = self.collection.key_at(index).cloned();
+            }
+            Some(_) => {}
+            None => self.active = None,
         }
+        self.active.as_ref()
+    }
 
-        let next = match self.active {
-            None => Some(0),
-            Some(index) if index + 1 < len => Some(index + 1),
-            Some(_) if self.looped => Some(0),
-            Some(_) => None,
+    pub fn move_next(&mut self) -> Option<usize> {
+        let len = self.len();
+        let (head, tail) = match self.active() {
+            None => (0..len, 0..0),
+            Some(index) if self.looped => (index + 1..len, 0..index + 1),
+            Some(index) => (index + 1..len, 0..0),
         };
+        self.move_within(head.chain(tail))
+    }
 
-        if let Some(index) = next {
-            self.active = Some(index);
-        }
+    pub fn move_prev(&mut self) -> Option<usize> {
+        let len = self.len();
+        let (head, tail) = match self.active() {
+            None => (0..len, 0..0),
+            Some(index) if self.looped => (0..index, index..len),
+            Some(index) => (0..index, 0..0),
+        };
+        self.move_within(head.rev().chain(tail.rev()))
+    }
 
-        next
+    pub fn move_first(&mut self) -> Option<usize> {
+        self.move_within(0..self.len())
     }
 
-    pub fn move_prev(&mut self) -> Option<usize> {
-        let len = self.len;
-        if len == 0 {
-            self.active = None;
-            return None;
-        }
+    pub fn move_last(&mut self) -> Option<usize> {
+        self.move_within((0..self.len()).rev())
+    }
 
-        let prev = match self.active {
-            None => Some(len.saturating_sub(1)),
-            Some(index) if index > 0 => Some(index - 1),
-            Some(_) if self.looped => Some(len.saturating_sub(1)),
-            Some(_) => None,
+    pub fn move_page_next(&mut self) -> Option<usize> {
+        let len = self.len();
+        let Some(index) = self.active() else {
+            return self.move_first();
         };
+        let target = index.saturating_add(self.page_size).min(len.saturating_sub(1));
+        self.move_within((target..len).chain((index + 1..target).rev()))
+    }
 
-        if let Some(index) = prev {
-            self.active = Some(index);
-        }
+    pub fn move_page_prev(&mut self) -> Option<usize> {
+        let Some(index) = self.active() else {
+            return self.move_last();
+        };
+        let target = index.saturating_sub(self.page_size);
+        self.move_within((0..target + 1).rev().chain(target + 1..index))
+    }
 
-        prev
+    pub fn type_char(&mut self, ch: char, now: u64) -> Option<usize> {
+        let active = self.active();
+        let focusable_when_disabled = self.focusable_when_disabled;
+        let found = self.typeahead.type_char(
+            ch,
+            now,
+            self.collection.items(),
+            active,
+            |item| item.text(),
+            |item| item.disabled() && !focusable_when_disabled,
+        );
+        if let Some(index) = found {
+            self.active = self.collection.key_at(index).cloned();
+        }
+        found
     }
 
-    pub fn move_first(&mut self) -> Option<usize> {
-        if self.len == 0 {
-            self.active = None;
-            return None;
+    pub fn apply(&mut self, action: RovingFocusAction, now: u64) -> Option<usize> {
+        match action {
+            RovingFocusAction::Next => self.move_next(),
+            RovingFocusAction::Prev => self.move_prev(),
+            RovingFocusAction::First => self.move_first(),
+            RovingFocusAction::Last => self.move_last(),
+            RovingFocusAction::PageNext => self.move_page_next(),
+            RovingFocusAction::PagePrev => self.move_page_prev(),
+            RovingFocusAction::Typeahead(ch) => self.type_char(ch, now),
         }
-        self.active = Some(0);
-        self.active
     }
 
-    pub fn move_last(&mut self) -> Option<usize> {
-        if self.len == 0 {
+    pub fn tab_stop(&self) -> Option<usize> {
+        self.active()
+            .filter(|index| self.is_focusable_at(*index))
+            .or_else(|| self.first_focusable())
+    }
+
+    fn move_within(&mut self, mut candidates: impl Iterator<Item = usize>) -> Option<usize> {
+        if self.first_focusable().is_none() {
             self.active = None;
             return None;
         }
-        self.active = Some(self.len - 1);
-        self.active
+        let next = candidates.find(|index| self.is_focusable_at(*index));
+        if let Some(index) = next {
+            self.active = self.collection.key_at(index).cloned();
+        }
+        next
+    }
+
+    pub fn first_focusable(&self) -> Option<usize> {
+        (0..self.len()).find(|index| self.is_focusable_at(*index))
     }
 
     fn clamp_active(&self, active: Option<usize>) -> Option<usize> {
         match active {
             Some(index) => {
-                if self.len == 0 {
+                if self.is_empty() {
                     None
-                } else if index < self.len {
+                } else if index < self.len() {
                     Some(index)
                 } else {
-                    Some(self.len - 1)
+                    Some(self.len() - 1)
                 }
             }
             None => None,
@@ -193,10 +337,14 @@ mod tests {
     use super::{
         roving_focus_action_from_key,
         roving_focus_next_index,
+        roving_focus_typeahead_from_key,
         RovingFocus,
         RovingFocusAction,
         RovingFocusOrientation,
     };
+    use crate::collection::{Collection, CollectionItem};
+    use crate::direction::Direction;
+    use crate::keyboard::{KeyInput, Modifiers};
 
     #[test]
     fn roving_focus_wraps_when_looped() {
@@ -212,6 +360,65 @@ mod tests {
         assert_eq!(focus.active(), Some(2));
     }
 
+    #[test]
+    fn roving_focus_skips_disabled_items() {
+        let collection: Collection<usize> = (0..4)
+            .map(|index| CollectionItem::new(index).with_disabled(index == 0 || index == 2))
+            .collect();
+        let mut focus = RovingFocus::from_collection(collection, true);
+        assert_eq!(focus.active(), Some(1));
+        assert_eq!(focus.move_next(), Some(3));
+        assert_eq!(focus.move_next(), Some(1));
+        assert_eq!(focus.move_first(), Some(1));
+        assert_eq!(focus.set_active(Some(2)), Some(1));
+
+        focus.set_focusable_when_disabled(true);
+        assert_eq!(focus.move_next(), Some(2));
+
+        focus.set_focusable_when_disabled(false);
+        for index in [1, 3] {
+            focus.set_disabled(&index, true);
+        }
+        assert_eq!(focus.move_next(), None);
+        assert_eq!(focus.active(), None);
+        assert_eq!(focus.tab_stop(), None);
+    }
+
+    #[test]
+    fn roving_focus_pages_and_typeahead() {
+        let collection: Collection<usize> = ["Apple", "Banana", "Blueberry", "Cherry", "Date"]
+            .into_iter()
+            .enumerate()
+            .map(|(index, text)| {
+                CollectionItem::new(index)
+                    .with_text(text)
+                    .with_disabled(index == 3)
+            })
+            .collect();
+        let mut focus = RovingFocus::from_collection(collection, false).with_page_size(3);
+        assert_eq!(focus.apply(RovingFocusAction::PageNext, 0), Some(4));
+        assert_eq!(focus.apply(RovingFocusAction::PagePrev, 0), Some(1));
+        assert_eq!(focus.apply(RovingFocusAction::Typeahead('b'), 0), Some(2));
+        assert_eq!(focus.apply(RovingFocusAction::Typeahead('c'), 1000), None);
+        assert_eq!(
+            roving_focus_action_from_key("PageDown", RovingFocusOrientation::Vertical, Direction::Ltr),
+            Some(RovingFocusAction::PageNext)
+        );
+        assert_eq!(
+            roving_focus_action_from_key("b", RovingFocusOrientation::Vertical, Direction::Ltr),
+            None
+        );
+        assert_eq!(
+            roving_focus_typeahead_from_key("b"),
+            Some(RovingFocusAction::Typeahead('b'))
+        );
+
+        let mut typed = focus.clone();
+        typed.type_char('d', 2000);
+        typed.set_active(focus.active());
+        assert_eq!(typed, focus);
+    }
+
     #[test]
     fn roving_focus_handles_empty() {
         let mut focus = RovingFocus::new(0);
@@ -224,24 +431,43 @@ mod tests {
         assert_eq!(
             roving_focus_action_from_key(
                 "ArrowLeft",
-                RovingFocusOrientation::Horizontal
+                RovingFocusOrientation::Horizontal,
+                Direction::Ltr
             ),
             Some(RovingFocusAction::Prev)
         );
         assert_eq!(
             roving_focus_action_from_key(
                 "ArrowUp",
-                RovingFocusOrientation::Horizontal
+                RovingFocusOrientation::Horizontal,
+                Direction::Ltr
             ),
             None
         );
         assert_eq!(
             roving_focus_action_from_key(
                 "ArrowDown",
-                RovingFocusOrientation::Both
+                RovingFocusOrientation::Both,
+                Direction::Ltr
+            ),
+            Some(RovingFocusAction::Next)
+        );
+        assert_eq!(
+            roving_focus_action_from_key(
+                "ArrowLeft",
+                RovingFocusOrientation::Horizontal,
+                Direction::Rtl
             ),
             Some(RovingFocusAction::Next)
         );
+        assert_eq!(
+            roving_focus_action_from_key(
+                KeyInput::from("ArrowDown").with_modifiers(Modifiers::ALT),
+                RovingFocusOrientation::Both,
+                Direction::Ltr
+            ),
+            None
+        );
     }
 
     #[test]
@@ -0,0 +1,304 @@
+use alloc::vec::Vec;
+
+use crate::collection::Collection;
+use crate::keyboard::Modifiers;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionMode {
+    None,
+    Single,
+    Multiple,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionBehavior {
+    Toggle,
+    Replace,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectionManager<K> {
+    mode: SelectionMode,
+    behavior: SelectionBehavior,
+    disallow_empty: bool,
+    selected: Vec<K>,
+    anchor: Option<K>,
+    focus: Option<K>,
+}
+
+impl<K: PartialEq + Clone> SelectionManager<K> {
+    pub fn new(mode: SelectionMode) -> Self {
+        Self {
+            mode,
+            behavior: SelectionBehavior::Toggle,
+            disallow_empty: false,
+            selected: Vec::new(),
+            anchor: None,
+            focus: None,
+        }
+    }
+
+    pub fn with_behavior(mut self, behavior: SelectionBehavior) -> Self {
+        self.behavior = behavior;
+        self
+    }
+
+    pub fn with_disallow_empty(mut self, disallow_empty: bool) -> Self {
+        self.disallow_empty = disallow_empty;
+        self
+    }
+
+    pub fn with_selected(mut self, keys: impl IntoIterator<Item = K>) -> Self {
+        for key in keys {
+            if self.mode == SelectionMode::None {
+                break;
+            }
+            if self.mode == SelectionMode::Single {
+                self.selected.clear();
+            }
+            if !self.selected.contains(&key) {
+                self.selected.push(key.clone());
+            }
+            self.anchor = Some(key);
+        }
+        self
+    }
+
+    pub fn mode(&self) -> SelectionMode {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: SelectionMode) -> bool {
+        self.mode = mode;
+        let keep = match mode {
+            SelectionMode::None => 0,
+            SelectionMode::Single => 1,
+            SelectionMode::Multiple => self.selected.len(),
+        };
+        let changed = self.selected.len() > keep;
+        self.selected.truncate(keep);
+        changed
+    }
+
+    pub fn behavior(&self) -> SelectionBehavior {
+        self.behavior
+    }
+
+    pub fn set_behavior(&mut self, behavior: SelectionBehavior) {
+        self.behavior = behavior;
+    }
+
+    pub fn disallow_empty(&self) -> bool {
+        self.disallow_empty
+    }
+
+    pub fn set_disallow_empty(&mut self, disallow_empty: bool) {
+        self.disallow_empty = disallow_empty;
+    }
+
+    pub fn selected_keys(&self) -> &[K] {
+        &self.selected
+    }
+
+    pub fn first_selected(&self) -> Option<&K> {
+        self.selected.first()
+    }
+
+    pub fn is_selected(&self, key: &K) -> bool {
+        self.selected.contains(key)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty()
+    }
+
+    pub fn anchor_key(&self) -> Option<&K> {
+        self.anchor.as_ref()
+    }
+
+    pub fn focused_key(&self) -> Option<&K> {
+        self.focus.as_ref()
+    }
+
+    pub fn set_focused_key(&mut self, key: Option<K>) {
+        self.focus = key;
+    }
+
+    pub fn select(&mut self, collection: &Collection<K>, key: &K) -> bool {
+        if !self.can_select(collection, key) {
+            return false;
+        }
+        self.anchor = Some(key.clone());
+        self.focus = Some(key.clone());
+        self.replace_with(alloc::vec![key.clone()])
+    }
+
+    pub fn toggle(&mut self, collection: &Collection<K>, key: &K) -> bool {
+        if !self.can_select(collection, key) {
+            return false;
+        }
+        self.focus = Some(key.clone());
+        if self.is_selected(key) {
+            if self.disallow_empty && self.selected.len() == 1 {
+                return false;
+            }
+            self.selected.retain(|selected| selected != key);
+            if self.anchor.as_ref() == Some(key) {
+                self.anchor = self.selected.last().cloned();
+            }
+            return true;
+        }
+        self.anchor = Some(key.clone());
+        if self.mode == SelectionMode::Single {
+            return self.replace_with(alloc::vec![key.clone()]);
+        }
+        self.selected.push(key.clone());
+        true
+    }
+
+    pub fn extend_to(&mut self, collection: &Collection<K>, key: &K) -> bool {
+        if self.mode != SelectionMode::Multiple {
+            return self.select(collection, key);
+        }
+        if !self.can_select(collection, key) {
+            return false;
+        }
+        let Some(to) = collection.index_of(key) else {
+            return false;
+        };
+        let from = self
+            .anchor
+            .as_ref()
+            .and_then(|anchor| collection.index_of(anchor))
+            .unwrap_or(to);
+        let (start, end) = if from <= to { (from, to) } else { (to, from) };
+        let range = collection.items()[start..=end]
+            .iter()
+            .filter(|item| !item.disabled())
+            .map(|item| item.key().clone())
+            .collect();
+        self.focus = Some(key.clone());
+        if self.anchor.is_none() {
+            self.anchor = Some(key.clone());
+        }
+        self.replace_with(range)
+    }
+
+    pub fn press(&mut self, collection: &Collection<K>, key: &K, modifiers: Modifiers) -> bool {
+        if modifiers.shift && self.mode == SelectionMode::Multiple {
+            return self.extend_to(collection, key);
+        }
+        if modifiers.has_command() || self.behavior == SelectionBehavior::Toggle {
+            return self.toggle(collection, key);
+        }
+        self.select(collection, key)
+    }
+
+    pub fn select_all(&mut self, collection: &Collection<K>) -> bool {
+        if self.mode != SelectionMode::Multiple {
+            return false;
+        }
+        let all = collection
+            .items()
+            .iter()
+            .filter(|item| !item.disabled())
+            .map(|item| item.key().clone())
+            .collect();
+        self.replace_with(all)
+    }
+
+    pub fn clear(&mut self) -> bool {
+        if self.disallow_empty || self.selected.is_empty() {
+            return false;
+        }
+        self.selected.clear();
+        self.anchor = None;
+        true
+    }
+
+    pub fn retain(&mut self, collection: &Collection<K>) -> bool {
+        let before = self.selected.len();
+        self.selected.retain(|key| collection.contains(key));
+        if self.anchor.as_ref().is_some_and(|key| !collection.contains(key)) {
+            self.anchor = self.selected.last().cloned();
+        }
+        if self.focus.as_ref().is_some_and(|key| !collection.contains(key)) {
+            self.focus = None;
+        }
+        self.selected.len() != before
+    }
+
+    fn can_select(&self, collection: &Collection<K>, key: &K) -> bool {
+        self.mode != SelectionMode::None
+            && collection.contains(key)
+            && !collection.is_disabled(key)
+    }
+
+    fn replace_with(&mut self, keys: Vec<K>) -> bool {
+        if keys.is_empty() && self.disallow_empty {
+            return false;
+        }
+        if keys == self.selected {
+            return false;
+        }
+        self.selected = keys;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SelectionBehavior, SelectionManager, SelectionMode};
+    use crate::collection::{Collection, CollectionItem};
+    use crate::keyboard::Modifiers;
+
+    fn letters() -> Collection<char> {
+        ['a', 'b', 'c', 'd', 'e']
+            .into_iter()
+            .map(|key| CollectionItem::new(key).with_disabled(key == 'c'))
+            .collect()
+    }
+
+    #[test]
+    fn selection_extends_range_from_anchor_skipping_disabled() {
+        let collection = letters();
+        let mut selection = SelectionManager::new(SelectionMode::Multiple);
+        assert!(selection.select(&collection, &'b'));
+        assert!(selection.extend_to(&collection, &'e'));
+        assert_eq!(selection.selected_keys(), ['b', 'd', 'e']);
+        assert!(selection.extend_to(&collection, &'a'));
+        assert_eq!(selection.selected_keys(), ['a', 'b']);
+        assert_eq!(selection.anchor_key(), Some(&'b'));
+        assert!(!selection.toggle(&collection, &'c'));
+    }
+
+    #[test]
+    fn selection_press_respects_behavior_and_modifiers() {
+        let collection = letters();
+        let mut selection = SelectionManager::new(SelectionMode::Multiple)
+            .with_behavior(SelectionBehavior::Replace);
+        selection.press(&collection, &'a', Modifiers::NONE);
+        selection.press(&collection, &'b', Modifiers::NONE);
+        assert_eq!(selection.selected_keys(), ['b']);
+        selection.press(&collection, &'d', Modifiers::CTRL);
+        assert_eq!(selection.selected_keys(), ['b', 'd']);
+
+        selection.set_behavior(SelectionBehavior::Toggle);
+        selection.press(&collection, &'b', Modifiers::NONE);
+        assert_eq!(selection.selected_keys(), ['d']);
+        assert!(selection.select_all(&collection));
+        assert_eq!(selection.selected_keys(), ['a', 'b', 'd', 'e']);
+    }
+
+    #[test]
+    fn selection_disallow_empty_keeps_last_key() {
+        let collection = letters();
+        let mut selection = SelectionManager::new(SelectionMode::Single)
+            .with_disallow_empty(true)
+            .with_selected(['a']);
+        assert!(!selection.toggle(&collection, &'a'));
+        assert!(!selection.clear());
+        assert!(selection.toggle(&collection, &'e'));
+        assert_eq!(selection.selected_keys(), ['e']);
+    }
+}
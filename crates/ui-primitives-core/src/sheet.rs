@@ -0,0 +1,390 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::aria::{Attr, AttrSet, DataState};
+use crate::dialog::{dialog_content_attrs, DialogModel, DialogState};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SnapPoint {
+    Pixels(f64),
+    Fraction(f64),
+}
+
+impl SnapPoint {
+    pub fn resolve(self, viewport: f64) -> f64 {
+        let height = match self {
+            SnapPoint::Pixels(pixels) => pixels,
+            SnapPoint::Fraction(fraction) => fraction * viewport,
+        };
+        height.clamp(0.0, viewport.max(0.0))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SheetRelease {
+    Snapped(usize),
+    Dismissed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SheetDrag {
+    start: f64,
+    offset: f64,
+    last_position: f64,
+    last_time: f64,
+    velocity: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SheetModel {
+    open: bool,
+    modal: bool,
+    dismissible: bool,
+    snap_points: Vec<SnapPoint>,
+    active_snap: usize,
+    viewport: f64,
+    dismiss_threshold: f64,
+    velocity_threshold: f64,
+    background_scale: f64,
+    drag: Option<SheetDrag>,
+}
+
+impl SheetModel {
+    pub fn new(open: bool) -> Self {
+        Self {
+            open,
+            modal: true,
+            dismissible: true,
+            snap_points: alloc::vec![SnapPoint::Fraction(1.0)],
+            active_snap: 0,
+            viewport: 0.0,
+            dismiss_threshold: 0.5,
+            velocity_threshold: 0.5,
+            background_scale: 0.06,
+            drag: None,
+        }
+    }
+
+    pub fn with_snap_points(mut self, snap_points: Vec<SnapPoint>) -> Self {
+        self.set_snap_points(snap_points);
+        self
+    }
+
+    pub fn with_viewport(mut self, viewport: f64) -> Self {
+        self.set_viewport(viewport);
+        self
+    }
+
+    pub fn with_dismiss_threshold(mut self, threshold: f64) -> Self {
+        self.dismiss_threshold = threshold.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_velocity_threshold(mut self, threshold: f64) -> Self {
+        self.velocity_threshold = threshold.abs();
+        self
+    }
+
+    pub fn with_background_scale(mut self, scale: f64) -> Self {
+        self.background_scale = scale.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn open(&self) -> bool {
+        self.open
+    }
+
+    pub fn modal(&self) -> bool {
+        self.modal
+    }
+
+    pub fn set_modal(&mut self, modal: bool) {
+        self.modal = modal;
+    }
+
+    pub fn dismissible(&self) -> bool {
+        self.dismissible
+    }
+
+    pub fn set_dismissible(&mut self, dismissible: bool) {
+        self.dismissible = dismissible;
+    }
+
+    pub fn state(&self) -> DialogState {
+        if self.open {
+            DialogState::Open
+        } else {
+            DialogState::Closed
+        }
+    }
+
+    pub fn dialog_model(&self) -> DialogModel {
+        let mut dialog = DialogModel::new(self.open);
+        dialog.set_modal(self.modal);
+        dialog
+    }
+
+    pub fn set_open(&mut self, open: bool) -> bool {
+        let changed = self.open != open;
+        self.open = open;
+        self.drag = None;
+        changed
+    }
+
+    pub fn toggle(&mut self) -> bool {
+        self.set_open(!self.open)
+    }
+
+    pub fn snap_points(&self) -> &[SnapPoint] {
+        &self.snap_points
+    }
+
+    pub fn set_snap_points(&mut self, snap_points: Vec<SnapPoint>) {
+        self.snap_points = if snap_points.is_empty() {
+            alloc::vec![SnapPoint::Fraction(1.0)]
+        } else {
+            snap_points
+        };
+        self.active_snap = self.active_snap.min(self.snap_points.len() - 1);
+    }
+
+    pub fn viewport(&self) -> f64 {
+        self.viewport
+    }
+
+    pub fn set_viewport(&mut self, viewport: f64) {
+        self.viewport = viewport.max(0.0);
+    }
+
+    pub fn active_snap(&self) -> usize {
+        self.active_snap
+    }
+
+    pub fn snap_to(&mut self, index: usize) -> bool {
+        if index >= self.snap_points.len() {
+            return false;
+        }
+        let changed = self.active_snap != index;
+        self.active_snap = index;
+        self.drag = None;
+        changed
+    }
+
+    pub fn snap_height(&self, index: usize) -> Option<f64> {
+        self.snap_points
+            .get(index)
+            .map(|point| point.resolve(self.viewport))
+    }
+
+    pub fn dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    pub fn drag_offset(&self) -> f64 {
+        self.drag.map(|drag| drag.offset).unwrap_or(0.0)
+    }
+
+    pub fn velocity(&self) -> f64 {
+        self.drag.map(|drag| drag.velocity).unwrap_or(0.0)
+    }
+
+    pub fn height(&self) -> f64 {
+        if !self.open {
+            return 0.0;
+        }
+        let base = self.snap_height(self.active_snap).unwrap_or(0.0);
+        (base - self.drag_offset()).max(0.0)
+    }
+
+    pub fn background_factor(&self) -> f64 {
+        let max = self.max_height();
+        if max <= 0.0 {
+            return 1.0;
+        }
+        let progress = (self.height() / max).clamp(0.0, 1.0);
+        1.0 - self.background_scale * progress
+    }
+
+    pub fn drag_start(&mut self, position: f64, time: f64) -> bool {
+        if !self.open {
+            return false;
+        }
+        self.drag = Some(SheetDrag {
+            start: position,
+            offset: 0.0,
+            last_position: position,
+            last_time: time,
+            velocity: 0.0,
+        });
+        true
+    }
+
+    pub fn drag_move(&mut self, position: f64, time: f64) -> f64 {
+        let base = self.snap_height(self.active_snap).unwrap_or(0.0);
+        let min_offset = base - self.max_height();
+        let Some(drag) = self.drag.as_mut() else {
+            return 0.0;
+        };
+        let elapsed = time - drag.last_time;
+        if elapsed > 0.0 {
+            drag.velocity = (position - drag.last_position) / elapsed;
+        }
+        drag.last_position = position;
+        drag.last_time = time;
+        drag.offset = (position - drag.start).max(min_offset);
+        drag.offset
+    }
+
+    pub fn drag_cancel(&mut self) {
+        self.drag = None;
+    }
+
+    pub fn drag_end(&mut self) -> Option<SheetRelease> {
+        let drag = self.drag.take()?;
+        let base = self.snap_height(self.active_snap).unwrap_or(0.0);
+        let height = (base - drag.offset).max(0.0);
+        let heights: Vec<f64> = self
+            .snap_points
+            .iter()
+            .map(|point| point.resolve(self.viewport))
+            .collect();
+        let lowest = heights.iter().copied().fold(f64::INFINITY, f64::min);
+
+        let fling_down = drag.velocity > self.velocity_threshold;
+        let fling_up = drag.velocity < -self.velocity_threshold;
+
+        let target = if fling_down {
+            sheet_nearest_where(&heights, height, |candidate| candidate < height)
+        } else if fling_up {
+            sheet_nearest_where(&heights, height, |candidate| candidate > height)
+                .or_else(|| sheet_nearest_where(&heights, height, |_| true))
+        } else {
+            sheet_nearest_where(&heights, height, |_| true)
+        };
+
+        let below_threshold = height < lowest * (1.0 - self.dismiss_threshold);
+        let flung_past_lowest = fling_down && target.is_none();
+        if self.dismissible && (below_threshold || flung_past_lowest) {
+            self.open = false;
+            return Some(SheetRelease::Dismissed);
+        }
+
+        let index = target
+            .or_else(|| sheet_nearest_where(&heights, height, |_| true))
+            .unwrap_or(0);
+        self.active_snap = index;
+        Some(SheetRelease::Snapped(index))
+    }
+
+    fn max_height(&self) -> f64 {
+        self.snap_points
+            .iter()
+            .map(|point| point.resolve(self.viewport))
+            .fold(0.0, f64::max)
+    }
+}
+
+fn sheet_nearest_where(
+    heights: &[f64],
+    height: f64,
+    accept: impl Fn(f64) -> bool,
+) -> Option<usize> {
+    heights
+        .iter()
+        .enumerate()
+        .filter(|(_, candidate)| accept(**candidate))
+        .min_by(|(_, a), (_, b)| {
+            (*a - height)
+                .abs()
+                .partial_cmp(&(*b - height).abs())
+                .unwrap_or(core::cmp::Ordering::Equal)
+        })
+        .map(|(index, _)| index)
+}
+
+pub fn sheet_content_attrs(
+    model: &SheetModel,
+    labelled_by: Option<&str>,
+    described_by: Option<&str>,
+) -> AttrSet {
+    dialog_content_attrs(&model.dialog_model(), labelled_by, described_by)
+        .with(Attr::Hidden(!model.open()))
+        .with(Attr::DataSnapPoint(model.active_snap()))
+        .with_if(model.dragging(), || Attr::DataDragging(true))
+        .with(Attr::Style(sheet_style_vars(model)))
+}
+
+pub fn sheet_background_attrs(model: &SheetModel) -> AttrSet {
+    AttrSet::new()
+        .with(Attr::DataSheetState(DataState::open(model.open())))
+        .with(Attr::Style(alloc::format!(
+            "--sheet-background-scale: {}",
+            model.background_factor()
+        )))
+}
+
+pub fn sheet_style_vars(model: &SheetModel) -> String {
+    alloc::format!(
+        "--sheet-drag-offset: {}px; --sheet-height: {}px; --sheet-background-scale: {}",
+        model.drag_offset(),
+        model.height(),
+        model.background_factor(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SheetModel, SheetRelease, SnapPoint};
+    use crate::dialog::DialogState;
+    use std::vec;
+
+    fn sheet() -> SheetModel {
+        SheetModel::new(true)
+            .with_viewport(800.0)
+            .with_snap_points(vec![SnapPoint::Pixels(200.0), SnapPoint::Fraction(0.5), SnapPoint::Fraction(1.0)])
+    }
+
+    #[test]
+    fn sheet_snaps_to_nearest_on_slow_release() {
+        let mut model = sheet();
+        model.snap_to(2);
+        model.drag_start(100.0, 0.0);
+        model.drag_move(350.0, 1000.0);
+        assert_eq!(model.height(), 550.0);
+        assert_eq!(model.drag_end(), Some(SheetRelease::Snapped(1)));
+        assert_eq!(model.height(), 400.0);
+    }
+
+    #[test]
+    fn sheet_fling_moves_one_snap_point() {
+        let mut model = sheet();
+        model.snap_to(1);
+        model.drag_start(400.0, 0.0);
+        model.drag_move(380.0, 10.0);
+        assert_eq!(model.drag_end(), Some(SheetRelease::Snapped(2)));
+    }
+
+    #[test]
+    fn sheet_dismisses_past_threshold_or_fling() {
+        let mut model = sheet();
+        model.drag_start(0.0, 0.0);
+        model.drag_move(150.0, 1000.0);
+        assert_eq!(model.drag_end(), Some(SheetRelease::Dismissed));
+        assert_eq!(model.state(), DialogState::Closed);
+
+        let mut model = sheet();
+        model.drag_start(0.0, 0.0);
+        model.drag_move(20.0, 10.0);
+        assert_eq!(model.drag_end(), Some(SheetRelease::Dismissed));
+    }
+
+    #[test]
+    fn sheet_background_factor_tracks_height() {
+        let mut model = sheet();
+        model.snap_to(2);
+        assert_eq!(model.background_factor(), 0.94);
+        model.set_open(false);
+        assert_eq!(model.background_factor(), 1.0);
+        assert!(!model.dialog_model().open());
+    }
+}
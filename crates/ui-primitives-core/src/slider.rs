@@ -0,0 +1,338 @@
+use crate::aria::{Attr, AttrSet, Role};
+use crate::direction::{Direction, Directional};
+use crate::keyboard::{Key, KeyInput};
+use crate::orientation::Orientation;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliderAction {
+    Increment,
+    Decrement,
+    IncrementPage,
+    DecrementPage,
+    Min,
+    Max,
+}
+
+pub fn slider_action_from_key(
+    input: impl Into<KeyInput>,
+    direction: Direction,
+) -> Option<SliderAction> {
+    let input = input.into();
+    if input.is_composing || input.modifiers.has_command() {
+        return None;
+    }
+    let shift = input.modifiers.shift;
+    match direction.flip_key(input.key) {
+        Key::ArrowRight | Key::ArrowUp if shift => Some(SliderAction::IncrementPage),
+        Key::ArrowLeft | Key::ArrowDown if shift => Some(SliderAction::DecrementPage),
+        Key::ArrowRight | Key::ArrowUp => Some(SliderAction::Increment),
+        Key::ArrowLeft | Key::ArrowDown => Some(SliderAction::Decrement),
+        Key::PageUp => Some(SliderAction::IncrementPage),
+        Key::PageDown => Some(SliderAction::DecrementPage),
+        Key::Home => Some(SliderAction::Min),
+        Key::End => Some(SliderAction::Max),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SliderModel {
+    value: f64,
+    min: f64,
+    max: f64,
+    step: f64,
+    page_step: f64,
+    orientation: Orientation,
+    direction: Direction,
+    disabled: bool,
+}
+
+impl SliderModel {
+    pub fn new(value: f64, min: f64, max: f64) -> Self {
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+        let mut model = Self {
+            value: min,
+            min,
+            max,
+            step: 1.0,
+            page_step: 10.0,
+            orientation: Orientation::Horizontal,
+            direction: Direction::Ltr,
+            disabled: false,
+        };
+        model.value = model.snap(value);
+        model
+    }
+
+    pub fn with_step(mut self, step: f64) -> Self {
+        self.step = if step > 0.0 { step } else { 0.0 };
+        self.value = self.snap(self.value);
+        self
+    }
+
+    pub fn with_page_step(mut self, page_step: f64) -> Self {
+        self.page_step = page_step.abs();
+        self
+    }
+
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    pub fn page_step(&self) -> f64 {
+        self.page_step
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    pub fn set_direction(&mut self, direction: Direction) {
+        self.direction = direction;
+    }
+
+    pub fn key_direction(&self) -> Direction {
+        match self.orientation {
+            Orientation::Horizontal => self.direction,
+            Orientation::Vertical => Direction::Ltr,
+        }
+    }
+
+    pub fn disabled(&self) -> bool {
+        self.disabled
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    pub fn percent(&self) -> f64 {
+        slider_value_to_percent(self.value, self.min, self.max)
+    }
+
+    pub fn set_value(&mut self, value: f64) -> bool {
+        if self.disabled {
+            return false;
+        }
+        let next = self.snap(value);
+        let changed = next != self.value;
+        self.value = next;
+        changed
+    }
+
+    pub fn set_percent(&mut self, percent: f64) -> bool {
+        self.set_value(slider_percent_to_value(percent, self.min, self.max))
+    }
+
+    pub fn set_from_pointer(&mut self, offset: f64, length: f64) -> bool {
+        if length <= 0.0 {
+            return false;
+        }
+        let percent = match self.orientation {
+            Orientation::Horizontal if self.direction.is_rtl() => 1.0 - offset / length,
+            Orientation::Horizontal => offset / length,
+            Orientation::Vertical => 1.0 - offset / length,
+        };
+        self.set_percent(percent)
+    }
+
+    pub fn apply(&mut self, action: SliderAction) -> bool {
+        let step = if self.step > 0.0 { self.step } else { 1.0 };
+        let page = if self.page_step > 0.0 {
+            self.page_step
+        } else {
+            step
+        };
+        let next = match action {
+            SliderAction::Increment => self.value + step,
+            SliderAction::Decrement => self.value - step,
+            SliderAction::IncrementPage => self.value + page,
+            SliderAction::DecrementPage => self.value - page,
+            SliderAction::Min => self.min,
+            SliderAction::Max => self.max,
+        };
+        self.set_value(next)
+    }
+
+    fn snap(&self, value: f64) -> f64 {
+        slider_snap_value(value, self.min, self.max, self.step)
+    }
+}
+
+pub fn slider_snap_value(value: f64, min: f64, max: f64, step: f64) -> f64 {
+    if value.is_nan() {
+        return min;
+    }
+    let clamped = value.clamp(min, max);
+    if step <= 0.0 {
+        return clamped;
+    }
+    let steps = round_f64((clamped - min) / step);
+    let snapped = min + steps * step;
+    let snapped = round_precision(snapped, step);
+    if snapped > max {
+        round_precision(snapped - step, step).max(min)
+    } else {
+        snapped
+    }
+}
+
+pub fn slider_value_to_percent(value: f64, min: f64, max: f64) -> f64 {
+    let range = max - min;
+    if range <= 0.0 {
+        return 0.0;
+    }
+    ((value - min) / range).clamp(0.0, 1.0)
+}
+
+pub fn slider_percent_to_value(percent: f64, min: f64, max: f64) -> f64 {
+    min + percent.clamp(0.0, 1.0) * (max - min)
+}
+
+pub(crate) fn round_f64(value: f64) -> f64 {
+    if value.is_nan() || value.is_infinite() || value.abs() >= 4_503_599_627_370_496.0 {
+        return value;
+    }
+    let truncated = value as i64 as f64;
+    let fraction = value - truncated;
+    if fraction >= 0.5 {
+        truncated + 1.0
+    } else if fraction <= -0.5 {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+fn round_precision(value: f64, step: f64) -> f64 {
+    let mut scale = 1.0;
+    let mut decimals = 0;
+    while decimals < 10 && round_f64(step * scale) != step * scale {
+        scale *= 10.0;
+        decimals += 1;
+    }
+    round_f64(value * scale) / scale
+}
+
+impl Directional for SliderModel {
+    fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    fn set_direction(&mut self, direction: Direction) {
+        self.direction = direction;
+    }
+}
+
+pub fn slider_thumb_attrs(
+    model: &SliderModel,
+    label: Option<&str>,
+    value_text: Option<&str>,
+) -> AttrSet {
+    AttrSet::new()
+        .with(Attr::Role(Role::Slider))
+        .with(Attr::AriaValueMin(model.min()))
+        .with(Attr::AriaValueMax(model.max()))
+        .with(Attr::AriaValueNow(model.value()))
+        .with_if(model.orientation() == Orientation::Vertical, || {
+            Attr::AriaOrientation(model.orientation())
+        })
+        .with(Attr::TabIndex(if model.disabled() { -1 } else { 0 }))
+        .with_if(model.disabled(), || Attr::AriaDisabled(true))
+        .with_if(model.disabled(), || Attr::DataDisabled(true))
+        .with_opt(label, |label| Attr::AriaLabel(label.into()))
+        .with_opt(value_text, |value_text| Attr::AriaValueText(value_text.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        slider_action_from_key,
+        slider_snap_value,
+        SliderAction,
+        SliderModel,
+    };
+    use crate::direction::Direction;
+    use crate::keyboard::{KeyInput, Modifiers};
+    use crate::orientation::Orientation;
+
+    #[test]
+    fn slider_snaps_to_step_within_range() {
+        assert_eq!(slider_snap_value(7.4, 0.0, 10.0, 2.0), 8.0);
+        assert_eq!(slider_snap_value(0.30000000000000004, 0.0, 1.0, 0.1), 0.3);
+        assert_eq!(slider_snap_value(12.0, 0.0, 10.0, 3.0), 9.0);
+    }
+
+    #[test]
+    fn slider_apply_actions() {
+        let mut model = SliderModel::new(50.0, 0.0, 100.0);
+        assert!(model.apply(SliderAction::Increment));
+        assert_eq!(model.value(), 51.0);
+        model.apply(SliderAction::DecrementPage);
+        assert_eq!(model.value(), 41.0);
+        model.apply(SliderAction::Max);
+        assert!(!model.apply(SliderAction::Increment));
+        assert_eq!(model.value(), 100.0);
+    }
+
+    #[test]
+    fn slider_pointer_respects_orientation() {
+        let mut model =
+            SliderModel::new(0.0, 0.0, 100.0).with_orientation(Orientation::Vertical);
+        model.set_from_pointer(25.0, 100.0);
+        assert_eq!(model.value(), 75.0);
+        assert_eq!(
+            slider_action_from_key("PageUp", Direction::Ltr),
+            Some(SliderAction::IncrementPage)
+        );
+        assert_eq!(
+            slider_action_from_key(
+                KeyInput::from("ArrowLeft").with_modifiers(Modifiers::SHIFT),
+                Direction::Ltr
+            ),
+            Some(SliderAction::DecrementPage)
+        );
+    }
+
+    #[test]
+    fn slider_rtl_flips_horizontal_keys_and_pointer() {
+        let mut model = SliderModel::new(0.0, 0.0, 100.0).with_direction(Direction::Rtl);
+        model.set_from_pointer(25.0, 100.0);
+        assert_eq!(model.value(), 75.0);
+        assert_eq!(
+            slider_action_from_key("ArrowLeft", model.key_direction()),
+            Some(SliderAction::Increment)
+        );
+        model = model.with_orientation(Orientation::Vertical);
+        assert_eq!(
+            slider_action_from_key("ArrowLeft", model.key_direction()),
+            Some(SliderAction::Decrement)
+        );
+    }
+}
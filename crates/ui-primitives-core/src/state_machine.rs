@@ -1,4 +1,11 @@
+use alloc::format;
+use alloc::rc::Rc;
+use alloc::string::String;
 use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::Write;
+
+use crate::clock::{Clock, TimerId};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Transition<S, E> {
@@ -13,26 +20,182 @@ pub struct TransitionResult<S> {
     pub next: S,
 }
 
-#[derive(Debug, Clone)]
-pub struct StateMachine<S, E> {
+pub type Guard<C, E> = Rc<dyn Fn(&C, &E) -> bool>;
+pub type Action<C, E> = Rc<dyn Fn(&mut C, &E)>;
+pub type StateHook<C> = Rc<dyn Fn(&mut C)>;
+
+struct TransitionBehavior<C, E> {
+    guard: Option<Guard<C, E>>,
+    action: Option<Action<C, E>>,
+}
+
+impl<C, E> Clone for TransitionBehavior<C, E> {
+    fn clone(&self) -> Self {
+        Self {
+            guard: self.guard.clone(),
+            action: self.action.clone(),
+        }
+    }
+}
+
+struct StateHooks<S, C> {
     state: S,
+    on_enter: Vec<StateHook<C>>,
+    on_exit: Vec<StateHook<C>>,
+}
+
+impl<S: Clone, C> Clone for StateHooks<S, C> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            on_enter: self.on_enter.clone(),
+            on_exit: self.on_exit.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DelayedTransition<S> {
+    pub from: S,
+    pub delay_ms: u64,
+    pub to: S,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArmedTimer {
+    pub id: TimerId,
+    pub deadline: u64,
+    delayed: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NondeterministicTransition<S, E> {
+    pub from: S,
+    pub event: E,
+    pub targets: Vec<S>,
+    pub guarded: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateMachineReport<S, E> {
+    pub nondeterministic: Vec<NondeterministicTransition<S, E>>,
+    pub unreachable: Vec<S>,
+    pub dead_ends: Vec<S>,
+    pub unaccepted_events: Vec<E>,
+}
+
+impl<S, E> StateMachineReport<S, E> {
+    pub fn is_clean(&self) -> bool {
+        self.nondeterministic.is_empty()
+            && self.unreachable.is_empty()
+            && self.dead_ends.is_empty()
+            && self.unaccepted_events.is_empty()
+    }
+}
+
+pub const STATE_MACHINE_TIMER_LIMIT: usize = 256;
+
+#[derive(Clone)]
+pub struct StateMachine<S, E, C = ()> {
+    initial: S,
+    state: S,
+    context: C,
     transitions: Vec<Transition<S, E>>,
+    behaviors: Vec<TransitionBehavior<C, E>>,
+    hooks: Vec<StateHooks<S, C>>,
+    clock: Option<Rc<dyn Clock>>,
+    elapsed: u64,
+    delayed: Vec<DelayedTransition<S>>,
+    delayed_behaviors: Vec<TransitionBehavior<C, ()>>,
+    timers: Vec<ArmedTimer>,
+    next_timer: TimerId,
+}
+
+pub struct TransitionBuilder<'a, C, E> {
+    behavior: &'a mut TransitionBehavior<C, E>,
 }
 
-impl<S, E> StateMachine<S, E> {
+impl<C, E> TransitionBuilder<'_, C, E> {
+    pub fn guard(self, guard: impl Fn(&C, &E) -> bool + 'static) -> Self {
+        self.behavior.guard = Some(Rc::new(guard));
+        self
+    }
+
+    pub fn action(self, action: impl Fn(&mut C, &E) + 'static) -> Self {
+        self.behavior.action = Some(Rc::new(action));
+        self
+    }
+}
+
+impl<S: Clone, E> StateMachine<S, E> {
     pub fn new(initial: S) -> Self {
+        Self::with_context(initial, ())
+    }
+}
+
+impl<S: Clone, E, C> StateMachine<S, E, C> {
+    pub fn with_context(initial: S, context: C) -> Self {
         Self {
+            initial: initial.clone(),
             state: initial,
+            context,
             transitions: Vec::new(),
+            behaviors: Vec::new(),
+            hooks: Vec::new(),
+            clock: None,
+            elapsed: 0,
+            delayed: Vec::new(),
+            delayed_behaviors: Vec::new(),
+            timers: Vec::new(),
+            next_timer: 0,
         }
     }
+}
+
+impl<S, E, C> StateMachine<S, E, C> {
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    pub fn set_clock(&mut self, clock: Option<Rc<dyn Clock>>) {
+        self.clock = clock;
+    }
+
+    pub fn now(&self) -> u64 {
+        self.clock.as_ref().map_or(self.elapsed, |clock| clock.now())
+    }
+
+    pub fn delayed_transitions(&self) -> &[DelayedTransition<S>] {
+        &self.delayed
+    }
+
+    pub fn armed_timers(&self) -> &[ArmedTimer] {
+        &self.timers
+    }
+
+    pub fn next_deadline(&self) -> Option<u64> {
+        self.timers.iter().map(|timer| timer.deadline).min()
+    }
+
+    pub fn initial(&self) -> &S {
+        &self.initial
+    }
 
     pub fn state(&self) -> &S {
         &self.state
     }
 
-    pub fn set_state(&mut self, state: S) {
-        self.state = state;
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    pub fn context_mut(&mut self) -> &mut C {
+        &mut self.context
+    }
+
+    pub fn into_context(self) -> C {
+        self.context
     }
 
     pub fn transitions(&self) -> &[Transition<S, E>] {
@@ -40,35 +203,1049 @@ impl<S, E> StateMachine<S, E> {
     }
 }
 
-impl<S: PartialEq, E: PartialEq> StateMachine<S, E> {
-    pub fn add_transition(&mut self, from: S, event: E, to: S) {
+impl<S: PartialEq, E: PartialEq, C> StateMachine<S, E, C> {
+    pub fn add_transition(&mut self, from: S, event: E, to: S) -> TransitionBuilder<'_, C, E> {
         self.transitions.push(Transition { from, event, to });
+        self.behaviors.push(TransitionBehavior {
+            guard: None,
+            action: None,
+        });
+        let behavior = self
+            .behaviors
+            .last_mut()
+            .expect("behavior pushed alongside transition");
+        TransitionBuilder { behavior }
+    }
+
+    pub fn after(&mut self, from: S, delay_ms: u64, to: S) -> TransitionBuilder<'_, C, ()> {
+        let index = self.delayed.len();
+        let armed = from == self.state;
+        self.delayed.push(DelayedTransition { from, delay_ms, to });
+        self.delayed_behaviors.push(TransitionBehavior {
+            guard: None,
+            action: None,
+        });
+        if armed {
+            self.arm_timer(index, self.now());
+        }
+        let behavior = self
+            .delayed_behaviors
+            .last_mut()
+            .expect("behavior pushed alongside delayed transition");
+        TransitionBuilder { behavior }
+    }
+
+    pub fn on_enter(&mut self, state: S, hook: impl Fn(&mut C) + 'static) {
+        state_hooks_entry(&mut self.hooks, state).on_enter.push(Rc::new(hook));
+    }
+
+    pub fn on_exit(&mut self, state: S, hook: impl Fn(&mut C) + 'static) {
+        state_hooks_entry(&mut self.hooks, state).on_exit.push(Rc::new(hook));
     }
 
     pub fn can_transition(&self, event: &E) -> bool {
+        self.find_transition(event).is_some()
+    }
+
+    pub fn set_state(&mut self, state: S) {
+        self.state = state;
+        self.rearm_timers();
+    }
+
+    pub fn cancel_timers(&mut self) {
+        self.timers.clear();
+    }
+
+    pub fn rearm_timers(&mut self) {
+        self.rearm_timers_from(self.now());
+    }
+
+    fn rearm_timers_from(&mut self, base: u64) {
+        self.timers.clear();
+        for index in 0..self.delayed.len() {
+            if self.delayed[index].from == self.state {
+                self.arm_timer(index, base);
+            }
+        }
+    }
+
+    fn arm_timer(&mut self, delayed: usize, base: u64) {
+        let id = self.next_timer;
+        self.next_timer += 1;
+        let deadline = base.saturating_add(self.delayed[delayed].delay_ms);
+        self.timers.push(ArmedTimer {
+            id,
+            deadline,
+            delayed,
+        });
+    }
+
+    fn find_transition(&self, event: &E) -> Option<usize> {
         self.transitions
             .iter()
-            .any(|transition| transition.from == self.state && &transition.event == event)
+            .zip(&self.behaviors)
+            .position(|(transition, behavior)| {
+                transition.from == self.state
+                    && &transition.event == event
+                    && behavior
+                        .guard
+                        .as_ref()
+                        .is_none_or(|guard| guard(&self.context, event))
+            })
+    }
+
+    fn run_hooks(&mut self, state: &S, enter: bool) {
+        for hook in state_hooks(&self.hooks, state, enter) {
+            hook(&mut self.context);
+        }
     }
 }
 
-impl<S: PartialEq + Clone, E: PartialEq> StateMachine<S, E> {
+impl<S: PartialEq + Clone, E: PartialEq, C> StateMachine<S, E, C> {
     pub fn trigger(&mut self, event: &E) -> Option<TransitionResult<S>> {
-        let transition = self.transitions.iter().find(|transition| {
-            transition.from == self.state && &transition.event == event
-        })?;
-
-        let previous = core::mem::replace(&mut self.state, transition.to.clone());
-        Some(TransitionResult {
-            previous,
-            next: self.state.clone(),
+        let index = self.find_transition(event)?;
+        let next = self.transitions[index].to.clone();
+        let action = self.behaviors[index].action.clone();
+        let now = self.now();
+        Some(self.transition_to(next, now, |context| {
+            if let Some(action) = action {
+                action(context, event);
+            }
+        }))
+    }
+
+    pub fn fire_timer(&mut self, id: TimerId) -> Option<TransitionResult<S>> {
+        let position = self.timers.iter().position(|timer| timer.id == id)?;
+        let timer = self.timers.remove(position);
+        self.elapsed = self.elapsed.max(timer.deadline);
+        let delayed = &self.delayed[timer.delayed];
+        if delayed.from != self.state {
+            return None;
+        }
+        let behavior = self.delayed_behaviors[timer.delayed].clone();
+        if behavior
+            .guard
+            .as_ref()
+            .is_some_and(|guard| !guard(&self.context, &()))
+        {
+            return None;
+        }
+        let next = delayed.to.clone();
+        Some(self.transition_to(next, timer.deadline, |context| {
+            if let Some(action) = behavior.action {
+                action(context, &());
+            }
+        }))
+    }
+
+    pub fn advance_to(&mut self, now: u64) -> Vec<TransitionResult<S>> {
+        self.elapsed = self.elapsed.max(now);
+        let mut results = Vec::new();
+        let mut fired = 0;
+        while fired < STATE_MACHINE_TIMER_LIMIT
+            && let Some(timer) = self
+            .timers
+            .iter()
+            .filter(|timer| timer.deadline <= now)
+            .min_by_key(|timer| (timer.deadline, timer.id))
+            .copied()
+        {
+            fired += 1;
+            if let Some(result) = self.fire_timer(timer.id) {
+                results.push(result);
+            }
+        }
+        results
+    }
+
+    fn transition_to(
+        &mut self,
+        next: S,
+        base: u64,
+        action: impl FnOnce(&mut C),
+    ) -> TransitionResult<S> {
+        let previous = self.state.clone();
+        self.timers.clear();
+        self.run_hooks(&previous, false);
+        action(&mut self.context);
+        self.state = next;
+        let next = self.state.clone();
+        self.run_hooks(&next, true);
+        self.rearm_timers_from(base);
+
+        TransitionResult { previous, next }
+    }
+}
+
+impl<S: PartialEq, E, C> StateMachine<S, E, C> {
+    pub fn states(&self) -> Vec<&S> {
+        let mut states = alloc::vec![&self.initial];
+        let edges = self
+            .transitions
+            .iter()
+            .map(|transition| (&transition.from, &transition.to))
+            .chain(self.delayed.iter().map(|delayed| (&delayed.from, &delayed.to)));
+        for (from, to) in edges {
+            for state in [from, to] {
+                if !states.contains(&state) {
+                    states.push(state);
+                }
+            }
+        }
+        states
+    }
+
+    pub fn to_dot(&self) -> String
+    where
+        S: fmt::Debug,
+        E: fmt::Debug,
+    {
+        self.to_dot_with(|state| format!("{state:?}"), |event| format!("{event:?}"))
+    }
+
+    pub fn to_dot_with(
+        &self,
+        state_label: impl Fn(&S) -> String,
+        event_label: impl Fn(&E) -> String,
+    ) -> String {
+        let mut out = String::from("digraph {\n    __start [shape=point];\n");
+        for state in self.states() {
+            let _ = writeln!(out, "    {};", dot_quote(&state_label(state)));
+        }
+        let _ = writeln!(out, "    __start -> {};", dot_quote(&state_label(&self.initial)));
+        for transition in &self.transitions {
+            let _ = writeln!(
+                out,
+                "    {} -> {} [label={}];",
+                dot_quote(&state_label(&transition.from)),
+                dot_quote(&state_label(&transition.to)),
+                dot_quote(&event_label(&transition.event)),
+            );
+        }
+        for delayed in &self.delayed {
+            let _ = writeln!(
+                out,
+                "    {} -> {} [label={}, style=dashed];",
+                dot_quote(&state_label(&delayed.from)),
+                dot_quote(&state_label(&delayed.to)),
+                dot_quote(&delay_label(delayed.delay_ms)),
+            );
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    pub fn to_mermaid(&self) -> String
+    where
+        S: fmt::Debug,
+        E: fmt::Debug,
+    {
+        self.to_mermaid_with(|state| format!("{state:?}"), |event| format!("{event:?}"))
+    }
+
+    pub fn to_mermaid_with(
+        &self,
+        state_label: impl Fn(&S) -> String,
+        event_label: impl Fn(&E) -> String,
+    ) -> String {
+        let states = self.states();
+        let id = |state: &S| mermaid_id(states.iter().position(|known| *known == state).unwrap_or(0));
+        let mut out = String::from("stateDiagram-v2\n");
+        for (index, state) in states.iter().enumerate() {
+            mermaid_declare(&mut out, &state_label(state), index, 1);
+        }
+        let _ = writeln!(out, "    [*] --> {}", id(&self.initial));
+        for transition in &self.transitions {
+            let _ = writeln!(
+                out,
+                "    {} --> {}: {}",
+                id(&transition.from),
+                id(&transition.to),
+                mermaid_text(&event_label(&transition.event)),
+            );
+        }
+        for delayed in &self.delayed {
+            let _ = writeln!(
+                out,
+                "    {} --> {}: {}",
+                id(&delayed.from),
+                id(&delayed.to),
+                delay_label(delayed.delay_ms),
+            );
+        }
+        out
+    }
+}
+
+impl<S: PartialEq + Clone, E: PartialEq + Clone, C> StateMachine<S, E, C> {
+    pub fn validate(&self, events: &[E]) -> StateMachineReport<S, E> {
+        let states = self.states();
+
+        let mut nondeterministic: Vec<NondeterministicTransition<S, E>> = Vec::new();
+        for (index, transition) in self.transitions.iter().enumerate() {
+            let seen = self.transitions[..index].iter().any(|earlier| {
+                earlier.from == transition.from && earlier.event == transition.event
+            });
+            if seen {
+                continue;
+            }
+            let competing: Vec<usize> = (index..self.transitions.len())
+                .filter(|other| {
+                    self.transitions[*other].from == transition.from
+                        && self.transitions[*other].event == transition.event
+                })
+                .collect();
+            if competing.len() > 1 {
+                nondeterministic.push(NondeterministicTransition {
+                    from: transition.from.clone(),
+                    event: transition.event.clone(),
+                    targets: competing
+                        .iter()
+                        .map(|other| self.transitions[*other].to.clone())
+                        .collect(),
+                    guarded: competing
+                        .iter()
+                        .all(|other| self.behaviors[*other].guard.is_some()),
+                });
+            }
+        }
+
+        let mut reachable: Vec<&S> = alloc::vec![&self.initial];
+        let mut cursor = 0;
+        while cursor < reachable.len() {
+            let from = reachable[cursor];
+            let targets = self
+                .transitions
+                .iter()
+                .filter(|transition| &transition.from == from)
+                .map(|transition| &transition.to)
+                .chain(
+                    self.delayed
+                        .iter()
+                        .filter(|delayed| &delayed.from == from)
+                        .map(|delayed| &delayed.to),
+                );
+            for target in targets {
+                if !reachable.contains(&target) {
+                    reachable.push(target);
+                }
+            }
+            cursor += 1;
+        }
+
+        let unreachable = states
+            .iter()
+            .filter(|state| !reachable.contains(state))
+            .map(|state| (*state).clone())
+            .collect();
+        let dead_ends = states
+            .iter()
+            .filter(|state| {
+                !self.transitions.iter().any(|transition| &&transition.from == *state)
+                    && !self.delayed.iter().any(|delayed| &&delayed.from == *state)
+            })
+            .map(|state| (*state).clone())
+            .collect();
+
+        let mut unaccepted_events: Vec<E> = Vec::new();
+        let candidates = events
+            .iter()
+            .chain(self.transitions.iter().map(|transition| &transition.event));
+        for event in candidates {
+            let accepted = self.transitions.iter().any(|transition| {
+                &transition.event == event && reachable.contains(&&transition.from)
+            });
+            if !accepted && !unaccepted_events.contains(event) {
+                unaccepted_events.push(event.clone());
+            }
+        }
+
+        StateMachineReport {
+            nondeterministic,
+            unreachable,
+            dead_ends,
+            unaccepted_events,
+        }
+    }
+}
+
+impl<S: fmt::Debug, E: fmt::Debug, C: fmt::Debug> fmt::Debug for StateMachine<S, E, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StateMachine")
+            .field("state", &self.state)
+            .field("context", &self.context)
+            .field("transitions", &self.transitions)
+            .field("delayed", &self.delayed)
+            .field("timers", &self.timers)
+            .finish_non_exhaustive()
+    }
+}
+
+fn dot_quote(label: &str) -> String {
+    let mut quoted = String::with_capacity(label.len() + 2);
+    quoted.push('"');
+    for ch in label.chars() {
+        match ch {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(ch);
+            }
+            '\n' => quoted.push_str("\\n"),
+            _ => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn mermaid_id(index: usize) -> String {
+    format!("s{index}")
+}
+
+fn mermaid_text(label: &str) -> String {
+    label
+        .chars()
+        .map(|ch| match ch {
+            '"' => '\'',
+            '\n' | ':' => ' ',
+            _ => ch,
+        })
+        .collect()
+}
+
+fn mermaid_declare(out: &mut String, label: &str, index: usize, depth: usize) {
+    let _ = writeln!(
+        out,
+        "{:indent$}state \"{}\" as {}",
+        "",
+        mermaid_text(label),
+        mermaid_id(index),
+        indent = depth * 4,
+    );
+}
+
+fn delay_label(delay_ms: u64) -> String {
+    format!("after {delay_ms}ms")
+}
+
+fn state_hooks_entry<S: PartialEq, C>(
+    hooks: &mut Vec<StateHooks<S, C>>,
+    state: S,
+) -> &mut StateHooks<S, C> {
+    let index = match hooks.iter().position(|entry| entry.state == state) {
+        Some(index) => index,
+        None => {
+            hooks.push(StateHooks {
+                state,
+                on_enter: Vec::new(),
+                on_exit: Vec::new(),
+            });
+            hooks.len() - 1
+        }
+    };
+    &mut hooks[index]
+}
+
+fn state_hooks<S: PartialEq, C>(
+    hooks: &[StateHooks<S, C>],
+    state: &S,
+    enter: bool,
+) -> Vec<StateHook<C>> {
+    match hooks.iter().find(|entry| &entry.state == state) {
+        Some(entry) if enter => entry.on_enter.clone(),
+        Some(entry) => entry.on_exit.clone(),
+        None => Vec::new(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateKind {
+    Atomic,
+    Compound,
+    Parallel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryKind {
+    None,
+    Shallow,
+    Deep,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatechartStep<S> {
+    pub exited: Vec<S>,
+    pub entered: Vec<S>,
+}
+
+#[derive(Debug, Clone)]
+struct StateNode<S> {
+    id: S,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    parallel: bool,
+    initial: Option<usize>,
+    history: HistoryKind,
+    memory: Option<Vec<usize>>,
+}
+
+#[derive(Clone)]
+pub struct Statechart<S, E, C = ()> {
+    nodes: Vec<StateNode<S>>,
+    active: Vec<usize>,
+    context: C,
+    transitions: Vec<Transition<S, E>>,
+    behaviors: Vec<TransitionBehavior<C, E>>,
+    hooks: Vec<StateHooks<S, C>>,
+}
+
+impl<S, E> Statechart<S, E> {
+    pub fn new(root: S) -> Self {
+        Self::with_context(root, ())
+    }
+}
+
+impl<S, E, C> Statechart<S, E, C> {
+    pub fn with_context(root: S, context: C) -> Self {
+        Self {
+            nodes: alloc::vec![StateNode {
+                id: root,
+                parent: None,
+                children: Vec::new(),
+                parallel: false,
+                initial: None,
+                history: HistoryKind::None,
+                memory: None,
+            }],
+            active: Vec::new(),
+            context,
+            transitions: Vec::new(),
+            behaviors: Vec::new(),
+            hooks: Vec::new(),
+        }
+    }
+
+    pub fn root(&self) -> &S {
+        &self.nodes[0].id
+    }
+
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    pub fn context_mut(&mut self) -> &mut C {
+        &mut self.context
+    }
+
+    pub fn into_context(self) -> C {
+        self.context
+    }
+
+    pub fn transitions(&self) -> &[Transition<S, E>] {
+        &self.transitions
+    }
+
+    pub fn started(&self) -> bool {
+        !self.active.is_empty()
+    }
+
+    pub fn active_states(&self) -> Vec<&S> {
+        let mut active = self.active.clone();
+        active.sort_unstable();
+        active.into_iter().map(|index| &self.nodes[index].id).collect()
+    }
+
+    pub fn active_leaves(&self) -> Vec<&S> {
+        let mut active = self.active.clone();
+        active.sort_unstable();
+        active
+            .into_iter()
+            .filter(|index| self.nodes[*index].children.is_empty())
+            .map(|index| &self.nodes[index].id)
+            .collect()
+    }
+
+    fn depth(&self, mut node: usize) -> usize {
+        let mut depth = 0;
+        while let Some(parent) = self.nodes[node].parent {
+            depth += 1;
+            node = parent;
+        }
+        depth
+    }
+
+    fn is_descendant_or_self(&self, node: usize, ancestor: usize) -> bool {
+        let mut current = Some(node);
+        while let Some(index) = current {
+            if index == ancestor {
+                return true;
+            }
+            current = self.nodes[index].parent;
+        }
+        false
+    }
+}
+
+impl<S: PartialEq, E: PartialEq, C> Statechart<S, E, C> {
+    pub fn add_state(&mut self, parent: &S, state: S) -> bool {
+        self.insert_node(parent, state, false)
+    }
+
+    pub fn add_parallel(&mut self, parent: &S, state: S) -> bool {
+        self.insert_node(parent, state, true)
+    }
+
+    pub fn set_initial(&mut self, parent: &S, child: &S) -> bool {
+        let (Some(parent), Some(child)) = (self.index_of(parent), self.index_of(child)) else {
+            return false;
+        };
+        if self.nodes[child].parent != Some(parent) {
+            return false;
+        }
+        self.nodes[parent].initial = Some(child);
+        true
+    }
+
+    pub fn set_history(&mut self, state: &S, history: HistoryKind) -> bool {
+        let Some(index) = self.index_of(state) else {
+            return false;
+        };
+        self.nodes[index].history = history;
+        true
+    }
+
+    pub fn kind(&self, state: &S) -> Option<StateKind> {
+        let node = &self.nodes[self.index_of(state)?];
+        Some(if node.parallel {
+            StateKind::Parallel
+        } else if node.children.is_empty() {
+            StateKind::Atomic
+        } else {
+            StateKind::Compound
         })
     }
+
+    pub fn parent(&self, state: &S) -> Option<&S> {
+        let parent = self.nodes[self.index_of(state)?].parent?;
+        Some(&self.nodes[parent].id)
+    }
+
+    pub fn is_in(&self, state: &S) -> bool {
+        self.index_of(state)
+            .is_some_and(|index| self.active.contains(&index))
+    }
+
+    pub fn add_transition(&mut self, from: S, event: E, to: S) -> TransitionBuilder<'_, C, E> {
+        self.transitions.push(Transition { from, event, to });
+        self.behaviors.push(TransitionBehavior {
+            guard: None,
+            action: None,
+        });
+        let behavior = self
+            .behaviors
+            .last_mut()
+            .expect("behavior pushed alongside transition");
+        TransitionBuilder { behavior }
+    }
+
+    pub fn on_enter(&mut self, state: S, hook: impl Fn(&mut C) + 'static) {
+        state_hooks_entry(&mut self.hooks, state).on_enter.push(Rc::new(hook));
+    }
+
+    pub fn on_exit(&mut self, state: S, hook: impl Fn(&mut C) + 'static) {
+        state_hooks_entry(&mut self.hooks, state).on_exit.push(Rc::new(hook));
+    }
+
+    pub fn can_transition(&self, event: &E) -> bool {
+        !self.select_transitions(event).is_empty()
+    }
+
+    fn insert_node(&mut self, parent: &S, state: S, parallel: bool) -> bool {
+        if self.index_of(&state).is_some() {
+            return false;
+        }
+        let Some(parent) = self.index_of(parent) else {
+            return false;
+        };
+        let index = self.nodes.len();
+        self.nodes.push(StateNode {
+            id: state,
+            parent: Some(parent),
+            children: Vec::new(),
+            parallel,
+            initial: None,
+            history: HistoryKind::None,
+            memory: None,
+        });
+        self.nodes[parent].children.push(index);
+        true
+    }
+
+    fn select_transitions(&self, event: &E) -> Vec<usize> {
+        let mut leaves: Vec<usize> = self
+            .active
+            .iter()
+            .copied()
+            .filter(|index| self.nodes[*index].children.is_empty())
+            .collect();
+        leaves.sort_unstable();
+
+        let mut selected: Vec<usize> = Vec::new();
+        for leaf in leaves {
+            let mut current = Some(leaf);
+            while let Some(node) = current {
+                if let Some(transition) = self.enabled_transition(node, event) {
+                    if !selected.contains(&transition) {
+                        selected.push(transition);
+                    }
+                    break;
+                }
+                current = self.nodes[node].parent;
+            }
+        }
+        selected
+    }
+
+    fn enabled_transition(&self, node: usize, event: &E) -> Option<usize> {
+        let id = &self.nodes[node].id;
+        self.transitions
+            .iter()
+            .zip(&self.behaviors)
+            .position(|(transition, behavior)| {
+                &transition.from == id
+                    && &transition.event == event
+                    && behavior
+                        .guard
+                        .as_ref()
+                        .is_none_or(|guard| guard(&self.context, event))
+            })
+    }
+}
+
+impl<S: PartialEq + Clone, E: PartialEq, C> Statechart<S, E, C> {
+    pub fn start(&mut self) -> Vec<S> {
+        if self.started() {
+            return Vec::new();
+        }
+        let mut entered = Vec::new();
+        self.enter_node(0, &[], &mut entered);
+        self.run_entry_hooks(&entered)
+    }
+
+    pub fn trigger(&mut self, event: &E) -> Option<StatechartStep<S>> {
+        if !self.started() {
+            self.start();
+        }
+        let selected = self.select_transitions(event);
+        if selected.is_empty() {
+            return None;
+        }
+
+        let mut step = StatechartStep {
+            exited: Vec::new(),
+            entered: Vec::new(),
+        };
+        for transition in selected {
+            let Some(source) = self.index_of(&self.transitions[transition].from) else {
+                continue;
+            };
+            let Some(target) = self.index_of(&self.transitions[transition].to) else {
+                continue;
+            };
+            if !self.active.contains(&source) {
+                continue;
+            }
+            let domain = self.transition_domain(source, target);
+
+            let mut exiting: Vec<usize> = self
+                .active
+                .iter()
+                .copied()
+                .filter(|index| *index != domain && self.is_descendant_or_self(*index, domain))
+                .collect();
+            exiting.sort_by(|a, b| self.depth(*b).cmp(&self.depth(*a)).then(b.cmp(a)));
+            for node in &exiting {
+                self.remember_history(*node);
+            }
+            for node in exiting {
+                self.active.retain(|index| *index != node);
+                let id = self.nodes[node].id.clone();
+                for hook in state_hooks(&self.hooks, &id, false) {
+                    hook(&mut self.context);
+                }
+                step.exited.push(id);
+            }
+
+            if let Some(action) = self.behaviors[transition].action.clone() {
+                action(&mut self.context, event);
+            }
+
+            let mut entered = Vec::new();
+            self.enter_node(domain, &[target], &mut entered);
+            step.entered.extend(self.run_entry_hooks(&entered));
+        }
+        Some(step)
+    }
+
+    fn transition_domain(&self, source: usize, target: usize) -> usize {
+        let mut lca = source;
+        while !self.is_descendant_or_self(target, lca) {
+            lca = self.nodes[lca].parent.unwrap_or(0);
+        }
+        if lca == source || lca == target {
+            self.nodes[lca].parent.unwrap_or(0)
+        } else {
+            lca
+        }
+    }
+
+    fn remember_history(&mut self, node: usize) {
+        let memory: Vec<usize> = match self.nodes[node].history {
+            HistoryKind::None => return,
+            HistoryKind::Shallow => self
+                .active
+                .iter()
+                .copied()
+                .filter(|index| self.nodes[*index].parent == Some(node))
+                .collect(),
+            HistoryKind::Deep => self
+                .active
+                .iter()
+                .copied()
+                .filter(|index| {
+                    *index != node
+                        && self.nodes[*index].children.is_empty()
+                        && self.is_descendant_or_self(*index, node)
+                })
+                .collect(),
+        };
+        self.nodes[node].memory = Some(memory);
+    }
+
+    fn enter_node(&mut self, node: usize, targets: &[usize], entered: &mut Vec<usize>) {
+        if !self.active.contains(&node) {
+            self.active.push(node);
+            entered.push(node);
+        }
+        if self.nodes[node].children.is_empty() {
+            return;
+        }
+        let children = self.nodes[node].children.clone();
+        if self.nodes[node].parallel {
+            for child in children {
+                self.enter_node(child, targets, entered);
+            }
+            return;
+        }
+
+        let toward_target = children.iter().copied().find(|child| {
+            targets
+                .iter()
+                .any(|target| self.is_descendant_or_self(*target, *child))
+        });
+        if let Some(child) = toward_target {
+            self.enter_node(child, targets, entered);
+            return;
+        }
+
+        if self.nodes[node].history != HistoryKind::None
+            && let Some(memory) = self.nodes[node].memory.clone()
+            && let Some(child) = children.iter().copied().find(|child| {
+                memory
+                    .iter()
+                    .any(|remembered| self.is_descendant_or_self(*remembered, *child))
+            })
+        {
+            self.enter_node(child, &memory, entered);
+            return;
+        }
+
+        let initial = self.nodes[node].initial.unwrap_or(children[0]);
+        self.enter_node(initial, &[], entered);
+    }
+
+    fn run_entry_hooks(&mut self, entered: &[usize]) -> Vec<S> {
+        let mut ids = Vec::with_capacity(entered.len());
+        for node in entered {
+            let id = self.nodes[*node].id.clone();
+            for hook in state_hooks(&self.hooks, &id, true) {
+                hook(&mut self.context);
+            }
+            ids.push(id);
+        }
+        ids
+    }
+}
+
+impl<S: PartialEq, E, C> Statechart<S, E, C> {
+    pub fn to_dot(&self) -> String
+    where
+        S: fmt::Debug,
+        E: fmt::Debug,
+    {
+        self.to_dot_with(|state| format!("{state:?}"), |event| format!("{event:?}"))
+    }
+
+    pub fn to_dot_with(
+        &self,
+        state_label: impl Fn(&S) -> String,
+        event_label: impl Fn(&E) -> String,
+    ) -> String {
+        let labels: Vec<String> = self.nodes.iter().map(|node| state_label(&node.id)).collect();
+        let mut out = String::from("digraph {\n    compound=true;\n    __start [shape=point];\n");
+        for child in &self.nodes[0].children {
+            self.dot_node(&mut out, *child, &labels, 1);
+        }
+        if let Some(initial) = self.initial_child(0) {
+            let _ = writeln!(out, "    __start -> {};", dot_quote(&labels[initial]));
+        }
+        for transition in &self.transitions {
+            let (Some(from), Some(to)) =
+                (self.index_of(&transition.from), self.index_of(&transition.to))
+            else {
+                continue;
+            };
+            let mut attrs = format!("label={}", dot_quote(&event_label(&transition.event)));
+            if !self.nodes[from].children.is_empty() {
+                let _ = write!(attrs, ", ltail={}", dot_quote(&format!("cluster_{}", labels[from])));
+            }
+            if !self.nodes[to].children.is_empty() {
+                let _ = write!(attrs, ", lhead={}", dot_quote(&format!("cluster_{}", labels[to])));
+            }
+            let _ = writeln!(
+                out,
+                "    {} -> {} [{}];",
+                dot_quote(&labels[from]),
+                dot_quote(&labels[to]),
+                attrs,
+            );
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    pub fn to_mermaid(&self) -> String
+    where
+        S: fmt::Debug,
+        E: fmt::Debug,
+    {
+        self.to_mermaid_with(|state| format!("{state:?}"), |event| format!("{event:?}"))
+    }
+
+    pub fn to_mermaid_with(
+        &self,
+        state_label: impl Fn(&S) -> String,
+        event_label: impl Fn(&E) -> String,
+    ) -> String {
+        let labels: Vec<String> = self.nodes.iter().map(|node| state_label(&node.id)).collect();
+        let mut out = String::from("stateDiagram-v2\n");
+        self.mermaid_children(&mut out, 0, &labels, 1);
+        for transition in &self.transitions {
+            let (Some(from), Some(to)) =
+                (self.index_of(&transition.from), self.index_of(&transition.to))
+            else {
+                continue;
+            };
+            let _ = writeln!(
+                out,
+                "    {} --> {}: {}",
+                mermaid_id(from),
+                mermaid_id(to),
+                mermaid_text(&event_label(&transition.event)),
+            );
+        }
+        out
+    }
+
+    fn index_of(&self, state: &S) -> Option<usize> {
+        self.nodes.iter().position(|node| &node.id == state)
+    }
+
+    fn initial_child(&self, node: usize) -> Option<usize> {
+        let node = &self.nodes[node];
+        if node.parallel {
+            return None;
+        }
+        node.initial.or_else(|| node.children.first().copied())
+    }
+
+    fn dot_node(&self, out: &mut String, node: usize, labels: &[String], depth: usize) {
+        let indent = depth * 4;
+        let label = dot_quote(&labels[node]);
+        if self.nodes[node].children.is_empty() {
+            let _ = writeln!(out, "{:indent$}{};", "", label);
+            return;
+        }
+        let _ = writeln!(
+            out,
+            "{:indent$}subgraph {} {{",
+            "",
+            dot_quote(&format!("cluster_{}", labels[node])),
+        );
+        let _ = writeln!(out, "{:inner$}label={};", "", label, inner = indent + 4);
+        if self.nodes[node].parallel {
+            let _ = writeln!(out, "{:inner$}style=dashed;", "", inner = indent + 4);
+        }
+        let _ = writeln!(
+            out,
+            "{:inner$}{} [shape=point, style=invis];",
+            "",
+            label,
+            inner = indent + 4,
+        );
+        for child in &self.nodes[node].children {
+            self.dot_node(out, *child, labels, depth + 1);
+        }
+        let _ = writeln!(out, "{:indent$}}}", "");
+    }
+
+    fn mermaid_children(&self, out: &mut String, node: usize, labels: &[String], depth: usize) {
+        let indent = depth * 4;
+        let children = &self.nodes[node].children;
+        for (position, child) in children.iter().enumerate() {
+            if self.nodes[node].parallel && position > 0 {
+                let _ = writeln!(out, "{:indent$}--", "");
+            }
+            mermaid_declare(out, &labels[*child], *child, depth);
+            if !self.nodes[*child].children.is_empty() {
+                let _ = writeln!(out, "{:indent$}state {} {{", "", mermaid_id(*child));
+                self.mermaid_children(out, *child, labels, depth + 1);
+                let _ = writeln!(out, "{:indent$}}}", "");
+            }
+        }
+        if let Some(initial) = self.initial_child(node) {
+            let _ = writeln!(out, "{:indent$}[*] --> {}", "", mermaid_id(initial));
+        }
+    }
+}
+
+impl<S: fmt::Debug, E: fmt::Debug, C: fmt::Debug> fmt::Debug for Statechart<S, E, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Statechart")
+            .field("nodes", &self.nodes)
+            .field("active", &self.active)
+            .field("context", &self.context)
+            .field("transitions", &self.transitions)
+            .finish_non_exhaustive()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{StateMachine, TransitionResult};
+    use super::{
+        HistoryKind,
+        NondeterministicTransition,
+        StateKind,
+        StateMachine,
+        Statechart,
+        STATE_MACHINE_TIMER_LIMIT,
+        TransitionResult,
+    };
+    use crate::clock::{Clock, ManualClock};
+    use alloc::format;
+    use alloc::rc::Rc;
+    use std::vec;
+    use std::vec::Vec;
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     enum DoorState {
@@ -108,4 +1285,304 @@ mod tests {
         assert!(machine.trigger(&DoorEvent::Close).is_none());
         assert!(!machine.can_transition(&DoorEvent::Close));
     }
+
+    #[derive(Debug, Default)]
+    struct DoorContext {
+        locked: bool,
+        opened: u32,
+        log: Vec<&'static str>,
+    }
+
+    #[test]
+    fn state_machine_guards_block_transition() {
+        let mut machine = StateMachine::with_context(DoorState::Closed, DoorContext::default());
+        machine
+            .add_transition(DoorState::Closed, DoorEvent::Open, DoorState::Open)
+            .guard(|context: &DoorContext, _| !context.locked);
+
+        machine.context_mut().locked = true;
+        assert!(!machine.can_transition(&DoorEvent::Open));
+        assert!(machine.trigger(&DoorEvent::Open).is_none());
+
+        machine.context_mut().locked = false;
+        assert!(machine.trigger(&DoorEvent::Open).is_some());
+        assert_eq!(machine.state(), &DoorState::Open);
+    }
+
+    #[test]
+    fn state_machine_runs_exit_action_enter_in_order() {
+        let mut machine = StateMachine::with_context(DoorState::Closed, DoorContext::default());
+        machine
+            .add_transition(DoorState::Closed, DoorEvent::Open, DoorState::Open)
+            .action(|context: &mut DoorContext, _| {
+                context.opened += 1;
+                context.log.push("action");
+            });
+        machine.on_exit(DoorState::Closed, |context| context.log.push("exit closed"));
+        machine.on_enter(DoorState::Open, |context| context.log.push("enter open"));
+
+        machine.trigger(&DoorEvent::Open);
+
+        let context = machine.into_context();
+        assert_eq!(context.opened, 1);
+        assert_eq!(context.log, ["exit closed", "action", "enter open"]);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum TooltipState {
+        Closed,
+        Opening,
+        Open,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum TooltipEvent {
+        Hover,
+        Leave,
+    }
+
+    fn tooltip(clock: &ManualClock) -> StateMachine<TooltipState, TooltipEvent> {
+        let clock: Rc<dyn Clock> = Rc::new(clock.clone());
+        let mut machine = StateMachine::new(TooltipState::Closed).with_clock(clock);
+        machine.add_transition(TooltipState::Closed, TooltipEvent::Hover, TooltipState::Opening);
+        machine.add_transition(TooltipState::Opening, TooltipEvent::Leave, TooltipState::Closed);
+        machine.add_transition(TooltipState::Open, TooltipEvent::Leave, TooltipState::Closed);
+        machine.after(TooltipState::Opening, 700, TooltipState::Open);
+        machine
+    }
+
+    #[test]
+    fn delayed_transition_fires_after_deadline() {
+        let clock = ManualClock::new(1_000);
+        let mut machine = tooltip(&clock);
+        assert!(machine.armed_timers().is_empty());
+
+        machine.trigger(&TooltipEvent::Hover);
+        assert_eq!(machine.next_deadline(), Some(1_700));
+        assert!(machine.advance_to(1_699).is_empty());
+        assert_eq!(
+            machine.advance_to(1_700),
+            [TransitionResult {
+                previous: TooltipState::Opening,
+                next: TooltipState::Open,
+            }]
+        );
+        assert!(machine.armed_timers().is_empty());
+    }
+
+    #[test]
+    fn delayed_transition_cancelled_on_exit() {
+        let clock = ManualClock::new(0);
+        let mut machine = tooltip(&clock);
+        machine.trigger(&TooltipEvent::Hover);
+        let timer = machine.armed_timers()[0].id;
+        clock.advance(300);
+        machine.trigger(&TooltipEvent::Leave);
+        assert!(machine.armed_timers().is_empty());
+        assert_eq!(machine.fire_timer(timer), None);
+        assert_eq!(machine.state(), &TooltipState::Closed);
+
+        clock.advance(100);
+        machine.trigger(&TooltipEvent::Hover);
+        assert_eq!(machine.next_deadline(), Some(1_100));
+    }
+
+    #[test]
+    fn delayed_transitions_chain_from_firing_deadline() {
+        let mut machine: StateMachine<TooltipState, TooltipEvent> =
+            StateMachine::new(TooltipState::Opening);
+        machine.after(TooltipState::Opening, 100, TooltipState::Open);
+        machine.after(TooltipState::Open, 50, TooltipState::Closed);
+        assert_eq!(machine.advance_to(1_000).len(), 2);
+        assert_eq!(machine.state(), &TooltipState::Closed);
+
+        machine.set_state(TooltipState::Opening);
+        assert_eq!(machine.next_deadline(), Some(1_100));
+        machine.set_state(TooltipState::Closed);
+        assert!(machine.armed_timers().is_empty());
+
+        machine.after(TooltipState::Closed, 0, TooltipState::Opening);
+        machine.after(TooltipState::Opening, 0, TooltipState::Closed);
+        machine.set_state(TooltipState::Closed);
+        assert_eq!(
+            machine.advance_to(1_000).len(),
+            STATE_MACHINE_TIMER_LIMIT
+        );
+        assert_eq!(machine.armed_timers().len(), 1);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Overlay {
+        Root,
+        Closed,
+        Open,
+        Menu,
+        Idle,
+        Submenu,
+        Focus,
+        Focused,
+        Blurred,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum OverlayEvent {
+        Open,
+        Close,
+        Enter,
+        Blur,
+    }
+
+    fn overlay_chart() -> Statechart<Overlay, OverlayEvent> {
+        let mut chart = Statechart::new(Overlay::Root);
+        chart.add_state(&Overlay::Root, Overlay::Closed);
+        chart.add_parallel(&Overlay::Root, Overlay::Open);
+        chart.add_state(&Overlay::Open, Overlay::Menu);
+        chart.add_state(&Overlay::Menu, Overlay::Idle);
+        chart.add_state(&Overlay::Menu, Overlay::Submenu);
+        chart.add_state(&Overlay::Open, Overlay::Focus);
+        chart.add_state(&Overlay::Focus, Overlay::Focused);
+        chart.add_state(&Overlay::Focus, Overlay::Blurred);
+        chart.set_history(&Overlay::Menu, HistoryKind::Shallow);
+        chart.add_transition(Overlay::Closed, OverlayEvent::Open, Overlay::Open);
+        chart.add_transition(Overlay::Open, OverlayEvent::Close, Overlay::Closed);
+        chart.add_transition(Overlay::Idle, OverlayEvent::Enter, Overlay::Submenu);
+        chart.add_transition(Overlay::Focused, OverlayEvent::Blur, Overlay::Blurred);
+        chart
+    }
+
+    #[test]
+    fn statechart_enters_initial_and_parallel_children() {
+        let mut chart = overlay_chart();
+        chart.start();
+        assert_eq!(chart.active_states(), [&Overlay::Root, &Overlay::Closed]);
+        assert_eq!(chart.kind(&Overlay::Open), Some(StateKind::Parallel));
+
+        chart.trigger(&OverlayEvent::Open).expect("open");
+        assert_eq!(chart.active_leaves(), [&Overlay::Idle, &Overlay::Focused]);
+        assert!(chart.is_in(&Overlay::Menu));
+        assert!(!chart.is_in(&Overlay::Closed));
+    }
+
+    #[test]
+    fn statechart_bubbles_events_to_ancestors() {
+        let mut chart = overlay_chart();
+        chart.trigger(&OverlayEvent::Open);
+        chart.trigger(&OverlayEvent::Enter);
+        chart.trigger(&OverlayEvent::Blur);
+        assert_eq!(chart.active_leaves(), [&Overlay::Submenu, &Overlay::Blurred]);
+
+        let step = chart.trigger(&OverlayEvent::Close).expect("close");
+        assert_eq!(
+            step.exited,
+            [Overlay::Blurred, Overlay::Submenu, Overlay::Focus, Overlay::Menu, Overlay::Open]
+        );
+        assert_eq!(step.entered, [Overlay::Closed]);
+        assert!(!chart.can_transition(&OverlayEvent::Close));
+    }
+
+    #[test]
+    fn statechart_restores_history() {
+        let mut chart = overlay_chart();
+        chart.trigger(&OverlayEvent::Open);
+        chart.trigger(&OverlayEvent::Enter);
+        chart.trigger(&OverlayEvent::Blur);
+        chart.trigger(&OverlayEvent::Close);
+        chart.trigger(&OverlayEvent::Open);
+        assert_eq!(chart.active_leaves(), [&Overlay::Submenu, &Overlay::Focused]);
+    }
+
+    #[test]
+    fn state_machine_exports_dot_and_mermaid() {
+        let clock = ManualClock::new(0);
+        let machine = tooltip(&clock);
+        assert_eq!(
+            machine.to_dot(),
+            "digraph {\n    __start [shape=point];\n    \"Closed\";\n    \"Opening\";\n    \"Open\";\n    __start -> \"Closed\";\n    \"Closed\" -> \"Opening\" [label=\"Hover\"];\n    \"Opening\" -> \"Closed\" [label=\"Leave\"];\n    \"Open\" -> \"Closed\" [label=\"Leave\"];\n    \"Opening\" -> \"Open\" [label=\"after 700ms\", style=dashed];\n}\n"
+        );
+        let mermaid = machine.to_mermaid_with(
+            |state| format!("{state:?} state"),
+            |event| format!("{event:?}"),
+        );
+        assert!(mermaid.starts_with("stateDiagram-v2\n    state \"Closed state\" as s0\n"));
+        assert!(mermaid.contains("    [*] --> s0\n"));
+        assert!(mermaid.contains("    s1 --> s2: after 700ms\n"));
+    }
+
+    #[test]
+    fn state_machine_exports_start_at_initial_state_with_unique_ids() {
+        let clock = ManualClock::new(0);
+        let mut machine = tooltip(&clock);
+        machine.trigger(&TooltipEvent::Hover);
+        assert_eq!(machine.state(), &TooltipState::Opening);
+        assert!(machine.to_dot().contains("    __start -> \"Closed\";\n"));
+
+        let mermaid = machine.to_mermaid_with(
+            |state| match state {
+                TooltipState::Closed => "a-b".into(),
+                TooltipState::Opening => "a_b".into(),
+                TooltipState::Open => "a b".into(),
+            },
+            |event| format!("{event:?}"),
+        );
+        assert!(mermaid.starts_with(
+            "stateDiagram-v2\n    state \"a-b\" as s0\n    state \"a_b\" as s1\n    state \"a b\" as s2\n    [*] --> s0\n"
+        ));
+        assert!(mermaid.contains("    s0 --> s1: Hover\n"));
+    }
+
+    #[test]
+    fn statechart_exports_nested_mermaid() {
+        let chart = overlay_chart();
+        let mermaid = chart.to_mermaid();
+        assert!(mermaid.starts_with("stateDiagram-v2\n    state \"Closed\" as s1\n    state \"Open\" as s2\n    state s2 {\n        state \"Menu\" as s3\n        state s3 {\n"));
+        assert!(mermaid.contains("            state \"Submenu\" as s5\n            [*] --> s4\n        }\n"));
+        assert!(mermaid.contains("        }\n        --\n        state \"Focus\" as s6\n"));
+        assert!(mermaid.contains("    }\n    [*] --> s1\n"));
+        assert!(mermaid.contains("    s2 --> s1: Close\n"));
+
+        let dot = chart.to_dot();
+        assert!(dot.contains("    subgraph \"cluster_Open\" {\n        label=\"Open\";\n        style=dashed;\n"));
+        assert!(dot.contains("\"Open\" -> \"Closed\" [label=\"Close\", ltail=\"cluster_Open\"];"));
+    }
+
+    #[test]
+    fn validate_reports_table_problems() {
+        let mut machine: StateMachine<DoorState, DoorEvent> = StateMachine::new(DoorState::Closed);
+        machine.add_transition(DoorState::Closed, DoorEvent::Open, DoorState::Open);
+        machine.add_transition(DoorState::Closed, DoorEvent::Open, DoorState::Closed);
+        let report = machine.validate(&[DoorEvent::Open, DoorEvent::Close]);
+        assert_eq!(
+            report.nondeterministic,
+            [NondeterministicTransition {
+                from: DoorState::Closed,
+                event: DoorEvent::Open,
+                targets: vec![DoorState::Open, DoorState::Closed],
+                guarded: false,
+            }]
+        );
+        assert!(report.unreachable.is_empty());
+        assert_eq!(report.dead_ends, [DoorState::Open]);
+        assert_eq!(report.unaccepted_events, [DoorEvent::Close]);
+        assert!(!report.is_clean());
+    }
+
+    #[test]
+    fn validate_walks_reachability_from_initial_state() {
+        let mut machine: StateMachine<u8, u8> = StateMachine::new(0);
+        machine.add_transition(0, 0, 1);
+        machine.add_transition(2, 1, 0);
+        machine.trigger(&0);
+        assert_eq!(machine.state(), &1);
+        let report = machine.validate(&[]);
+        assert_eq!(report.unreachable, [2]);
+        assert_eq!(report.unaccepted_events, [1]);
+    }
+
+    #[test]
+    fn validate_accepts_well_formed_machine() {
+        let clock = ManualClock::new(0);
+        let machine = tooltip(&clock);
+        let report = machine.validate(&[TooltipEvent::Hover, TooltipEvent::Leave]);
+        assert!(report.is_clean());
+    }
 }
@@ -1,4 +1,16 @@
-use crate::roving_focus::RovingFocus;
+use crate::aria::{Attr, AttrSet, DataState, Role};
+use crate::collection::{collection_resolve_key, Collection};
+use crate::direction::{Direction, Directional};
+use crate::keyboard::{Key, KeyInput};
+use crate::model::{Effect, Model, Outcome};
+use crate::orientation::Orientation;
+use crate::roving_focus::{
+    roving_focus_action_from_key,
+    roving_focus_typeahead_from_key,
+    RovingFocus,
+    RovingFocusAction,
+};
+use crate::selection::{SelectionManager, SelectionMode};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TabsActivation {
@@ -7,10 +19,19 @@ pub enum TabsActivation {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct TabsModel {
-    focus: RovingFocus,
-    selected: Option<usize>,
+pub enum TabsEvent<K = usize> {
+    Key { input: KeyInput, now: u64 },
+    Select(K),
+    Focus(K),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TabsModel<K = usize> {
+    focus: RovingFocus<K>,
+    selection: SelectionManager<K>,
     activation: TabsActivation,
+    orientation: Orientation,
+    direction: Direction,
 }
 
 impl TabsModel {
@@ -19,27 +40,88 @@ impl TabsModel {
     }
 
     pub fn with_activation(len: usize, activation: TabsActivation) -> Self {
-        let focus = RovingFocus::with_active(len, if len > 0 { Some(0) } else { None }, true);
-        let selected = if len > 0 { Some(0) } else { None };
+        Self::from_collection(Collection::indexed(len), activation)
+    }
+
+    pub fn set_len(&mut self, len: usize) {
+        self.set_collection(Collection::indexed(len));
+    }
+}
+
+impl<K: PartialEq + Clone> TabsModel<K> {
+    pub fn from_collection(collection: Collection<K>, activation: TabsActivation) -> Self {
+        let focus = RovingFocus::from_collection(collection, true);
+        let first = focus
+            .first_focusable()
+            .and_then(|index| focus.collection().key_at(index).cloned());
+        let selection = SelectionManager::new(SelectionMode::Single).with_selected(first);
         Self {
             focus,
-            selected,
+            selection,
             activation,
+            orientation: Orientation::Horizontal,
+            direction: Direction::Ltr,
         }
     }
 
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn collection(&self) -> &Collection<K> {
+        self.focus.collection()
+    }
+
+    pub fn set_collection(&mut self, collection: Collection<K>) {
+        let selected =
+            collection_resolve_key(self.focus.collection(), &collection, self.selected_key());
+        self.focus.set_collection(collection);
+        self.selection.retain(self.focus.collection());
+        self.set_selected(selected);
+    }
+
     pub fn len(&self) -> usize {
         self.focus.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.focus.is_empty()
+    }
+
     pub fn selected(&self) -> Option<usize> {
-        self.selected
+        self.collection().index_of(self.selected_key()?)
+    }
+
+    pub fn selected_key(&self) -> Option<&K> {
+        self.selection.first_selected()
+    }
+
+    pub fn selection(&self) -> &SelectionManager<K> {
+        &self.selection
     }
 
     pub fn focused(&self) -> Option<usize> {
         self.focus.active()
     }
 
+    pub fn focused_key(&self) -> Option<&K> {
+        self.focus.active_key()
+    }
+
+    pub fn tab_stop(&self) -> Option<usize> {
+        self.focus.tab_stop()
+    }
+
+    pub fn set_disabled(&mut self, key: &K, disabled: bool) -> bool {
+        self.focus.set_disabled(key, disabled)
+    }
+
     pub fn activation(&self) -> TabsActivation {
         self.activation
     }
@@ -48,70 +130,129 @@ impl TabsModel {
         self.activation = activation;
     }
 
-    pub fn set_len(&mut self, len: usize) {
-        self.focus.set_len(len);
-        self.selected = self.clamp_index(self.selected);
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    pub fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    pub fn set_direction(&mut self, direction: Direction) {
+        self.direction = direction;
+    }
+
+    pub fn handle_key(&mut self, input: impl Into<KeyInput>, now: u64) -> Option<usize> {
+        if !self.consume_key(input.into(), now) {
+            return None;
+        }
+        self.focused()
     }
 
     pub fn focus_index(&mut self, index: Option<usize>) -> Option<usize> {
         let focused = self.focus.set_active(index);
-        if self.activation == TabsActivation::Automatic {
-            self.selected = focused;
-        }
+        self.follow_focus();
         focused
     }
 
+    pub fn focus_key(&mut self, key: Option<&K>) -> Option<&K> {
+        self.focus.set_active_key(key);
+        self.follow_focus();
+        self.focus.active_key()
+    }
+
     pub fn focus_next(&mut self) -> Option<usize> {
         let focused = self.focus.move_next();
-        if self.activation == TabsActivation::Automatic {
-            self.selected = focused;
-        }
+        self.follow_focus();
         focused
     }
 
     pub fn focus_prev(&mut self) -> Option<usize> {
         let focused = self.focus.move_prev();
-        if self.activation == TabsActivation::Automatic {
-            self.selected = focused;
-        }
+        self.follow_focus();
         focused
     }
 
     pub fn focus_first(&mut self) -> Option<usize> {
         let focused = self.focus.move_first();
-        if self.activation == TabsActivation::Automatic {
-            self.selected = focused;
-        }
+        self.follow_focus();
         focused
     }
 
     pub fn focus_last(&mut self) -> Option<usize> {
         let focused = self.focus.move_last();
-        if self.activation == TabsActivation::Automatic {
-            self.selected = focused;
-        }
+        self.follow_focus();
         focused
     }
 
     pub fn select(&mut self, index: Option<usize>) -> Option<usize> {
-        self.selected = self.clamp_index(index);
+        let key = self
+            .clamp_index(index)
+            .and_then(|index| self.collection().key_at(index).cloned());
+        self.select_key(key.as_ref());
+        self.selected()
+    }
+
+    pub fn select_key(&mut self, key: Option<&K>) -> Option<&K> {
+        let key = key.filter(|key| self.collection().contains(key)).cloned();
+        self.set_selected(key);
         if self.activation == TabsActivation::Automatic {
-            self.focus.set_active(self.selected);
+            self.focus.set_active_key(self.selection.first_selected());
         }
-        self.selected
+        self.selected_key()
     }
 
     pub fn activate_focused(&mut self) -> Option<usize> {
         if self.activation == TabsActivation::Manual {
-            self.selected = self.focus.active();
+            self.set_selected(self.focus.active_key().cloned());
+        }
+        self.selected()
+    }
+
+    fn consume_key(&mut self, input: KeyInput, now: u64) -> bool {
+        if matches!(input.key, Key::Enter | Key::Space) && input.modifiers.is_empty() {
+            self.activate_focused();
+            return true;
+        }
+        let Some(action) =
+            roving_focus_action_from_key(input.clone(), self.orientation, self.direction)
+                .or_else(|| roving_focus_typeahead_from_key(input))
+        else {
+            return false;
+        };
+        let found = self.focus.apply(action, now);
+        if matches!(action, RovingFocusAction::Typeahead(_)) && found.is_none() {
+            return false;
+        }
+        self.follow_focus();
+        true
+    }
+
+    fn follow_focus(&mut self) {
+        if self.activation == TabsActivation::Automatic {
+            self.set_selected(self.focus.active_key().cloned());
+        }
+    }
+
+    fn set_selected(&mut self, key: Option<K>) {
+        match key {
+            Some(key) => {
+                self.selection.select(self.focus.collection(), &key);
+            }
+            None => {
+                self.selection.clear();
+            }
         }
-        self.selected
     }
 
     fn clamp_index(&self, index: Option<usize>) -> Option<usize> {
         match index {
             Some(index) => {
-                if self.len() == 0 {
+                if self.is_empty() {
                     None
                 } else if index < self.len() {
                     Some(index)
@@ -124,9 +265,109 @@ impl TabsModel {
     }
 }
 
+impl<K: PartialEq + Clone> Model for TabsModel<K> {
+    type Event = TabsEvent<K>;
+    type Key = K;
+
+    fn send(&mut self, event: TabsEvent<K>) -> Outcome<K> {
+        let focused = self.focused_key().cloned();
+        let selected = self.selected_key().cloned();
+        let key_event = matches!(event, TabsEvent::Key { .. });
+        let consumed = match event {
+            TabsEvent::Key { input, now } => self.consume_key(input, now),
+            TabsEvent::Select(key) => {
+                self.select_key(Some(&key));
+                false
+            }
+            TabsEvent::Focus(key) => {
+                self.focus_key(Some(&key));
+                false
+            }
+        };
+        let focus_moved = self.focused_key().filter(|key| focused.as_ref() != Some(*key));
+        let selection_changed = self.selected_key() != selected.as_ref();
+        let mut outcome = Outcome::none();
+        if key_event && (consumed || focus_moved.is_some() || selection_changed) {
+            outcome.push(Effect::PreventDefault);
+        }
+        if let Some(key) = focus_moved {
+            outcome.push(Effect::FocusItem(key.clone()));
+        }
+        if selection_changed {
+            outcome.push(Effect::SelectionChanged(self.selected_key().cloned()));
+            if let Some(item) = self.selected_key().and_then(|key| self.collection().get(key))
+                && self.focused_key() != Some(item.key())
+                && !item.text().is_empty()
+            {
+                outcome.push(Effect::Announce(item.text().into()));
+            }
+        }
+        outcome
+    }
+}
+
+impl<K: PartialEq + Clone> Directional for TabsModel<K> {
+    fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    fn set_direction(&mut self, direction: Direction) {
+        self.direction = direction;
+    }
+}
+
+pub fn tabs_list_attrs(orientation: Orientation, direction: Option<Direction>) -> AttrSet {
+    AttrSet::new()
+        .with(Attr::Role(Role::TabList))
+        .with_opt(direction, Attr::Dir)
+        .with_if(orientation == Orientation::Vertical, || {
+            Attr::AriaOrientation(orientation)
+        })
+}
+
+pub fn tabs_trigger_attrs<K: PartialEq + Clone>(
+    model: &TabsModel<K>,
+    index: usize,
+    trigger_id: Option<&str>,
+    controls_id: Option<&str>,
+) -> AttrSet {
+    let selected = model.selected() == Some(index);
+    let disabled = model.collection().is_disabled_at(index);
+    AttrSet::new()
+        .with(Attr::Role(Role::Tab))
+        .with(Attr::AriaSelected(selected))
+        .with(Attr::DataState(DataState::active(selected)))
+        .with(Attr::TabIndex(if model.tab_stop() == Some(index) { 0 } else { -1 }))
+        .with(Attr::Disabled(disabled))
+        .with_if(disabled, || Attr::AriaDisabled(true))
+        .with_opt(trigger_id, |id| Attr::Id(id.into()))
+        .with_opt(controls_id, |controls| Attr::AriaControls(controls.into()))
+}
+
+pub fn tabs_panel_attrs<K: PartialEq + Clone>(
+    model: &TabsModel<K>,
+    index: usize,
+    panel_id: Option<&str>,
+    labelled_by: Option<&str>,
+) -> AttrSet {
+    let selected = model.selected() == Some(index);
+    AttrSet::new()
+        .with(Attr::Role(Role::TabPanel))
+        .with(Attr::Hidden(!selected))
+        .with(Attr::TabIndex(0))
+        .with_opt(panel_id, |id| Attr::Id(id.into()))
+        .with_opt(labelled_by, |labelled_by| Attr::AriaLabelledBy(labelled_by.into()))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{TabsActivation, TabsModel};
+    use super::{tabs_trigger_attrs, TabsActivation, TabsEvent, TabsModel};
+    use crate::aria::{Attr, DataState, Role};
+    use crate::collection::{Collection, CollectionItem};
+    use crate::direction::Direction;
+    use crate::keyboard::KeyInput;
+    use crate::model::{Effect, Model};
+    use crate::orientation::Orientation;
 
     #[test]
     fn tabs_auto_activation_tracks_focus() {
@@ -152,4 +393,149 @@ mod tests {
         model.set_len(1);
         assert_eq!(model.selected(), Some(0));
     }
+
+    #[test]
+    fn tabs_selection_follows_key_across_reorders() {
+        let collection: Collection<&str> = ["one", "two", "three"]
+            .into_iter()
+            .map(CollectionItem::new)
+            .collect();
+        let mut model = TabsModel::from_collection(collection.clone(), TabsActivation::Automatic);
+        model.select_key(Some(&"two"));
+
+        let mut next = collection;
+        next.move_to(&"two", 2);
+        model.set_collection(next.clone());
+        assert_eq!(model.selected_key(), Some(&"two"));
+        assert_eq!(model.selected(), Some(2));
+
+        next.remove(&"two");
+        model.set_collection(next);
+        assert_eq!(model.selected_key(), Some(&"three"));
+        assert_eq!(model.focused_key(), Some(&"three"));
+    }
+
+    #[test]
+    fn tabs_skip_disabled_tabs_when_seeding_and_resolving_selection() {
+        let disabled_first: Collection<char> = ['a', 'b']
+            .into_iter()
+            .map(|key| CollectionItem::new(key).with_disabled(key == 'a'))
+            .collect();
+        let model = TabsModel::from_collection(disabled_first, TabsActivation::Automatic);
+        assert_eq!(model.selected_key(), Some(&'b'));
+        assert_eq!(model.focused_key(), Some(&'b'));
+
+        let collection: Collection<char> = ['a', 'b', 'c']
+            .into_iter()
+            .map(|key| CollectionItem::new(key).with_disabled(key == 'b'))
+            .collect();
+        let mut model = TabsModel::from_collection(collection.clone(), TabsActivation::Automatic);
+        assert_eq!(model.selected_key(), Some(&'a'));
+        let mut next = collection;
+        next.remove(&'a');
+        model.set_collection(next);
+        assert_eq!(model.selected_key(), Some(&'c'));
+        assert_eq!(model.focused_key(), Some(&'c'));
+        assert_eq!(model.tab_stop(), Some(1));
+    }
+
+    #[test]
+    fn tabs_handle_key_uses_own_orientation_and_direction() {
+        let collection: Collection<usize> = ["Alpha", "Beta", "Gamma"]
+            .into_iter()
+            .enumerate()
+            .map(|(index, text)| CollectionItem::new(index).with_text(text))
+            .collect();
+        let mut model = TabsModel::from_collection(collection, TabsActivation::Manual)
+            .with_orientation(Orientation::Vertical);
+        assert_eq!(model.handle_key("ArrowRight", 0), None);
+        assert_eq!(model.handle_key("ArrowDown", 0), Some(1));
+        assert_eq!(model.handle_key("g", 10), Some(2));
+        assert_eq!(model.handle_key("x", 1000), None);
+        assert_eq!(model.focused(), Some(2));
+        assert_eq!(model.handle_key("Enter", 20), Some(2));
+        assert_eq!(model.selected(), Some(2));
+
+        model.set_orientation(Orientation::Horizontal);
+        model.set_direction(Direction::Rtl);
+        assert_eq!(model.handle_key("ArrowLeft", 30), Some(0));
+        assert_eq!(model.handle_key("Escape", 40), None);
+    }
+
+    #[test]
+    fn tabs_send_reports_focus_and_selection_effects() {
+        let mut model = TabsModel::new(3);
+        let outcome = model.send(TabsEvent::Key {
+            input: KeyInput::from("End"),
+            now: 0,
+        });
+        assert_eq!(
+            outcome.effects(),
+            [
+                Effect::PreventDefault,
+                Effect::FocusItem(2),
+                Effect::SelectionChanged(Some(2)),
+            ]
+        );
+        let outcome = model.send(TabsEvent::Key {
+            input: KeyInput::from("Tab"),
+            now: 0,
+        });
+        assert!(outcome.is_empty());
+        assert_eq!(
+            model.send(TabsEvent::Select(0)).effects(),
+            [Effect::FocusItem(0), Effect::SelectionChanged(Some(0))]
+        );
+    }
+
+    #[test]
+    fn tabs_send_prevents_default_only_for_consumed_keys_and_announces() {
+        let collection: Collection<usize> = ["Alpha", "Beta", "Gamma"]
+            .into_iter()
+            .enumerate()
+            .map(|(index, text)| CollectionItem::new(index).with_text(text))
+            .collect();
+        let mut model = TabsModel::from_collection(collection, TabsActivation::Manual);
+        let key = |input: &str| TabsEvent::Key {
+            input: KeyInput::from(input),
+            now: 0,
+        };
+        assert_eq!(model.send(key("Enter")).effects(), [Effect::PreventDefault]);
+        assert!(model.send(key("x")).is_empty());
+        assert!(model.send(key("a")).is_empty());
+        assert_eq!(
+            model.send(TabsEvent::Select(2)).effects(),
+            [
+                Effect::SelectionChanged(Some(2)),
+                Effect::Announce("Gamma".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn tabs_trigger_attrs_are_typed() {
+        let model = TabsModel::new(2);
+        let attrs = tabs_trigger_attrs(&model, 1, None, Some("panel-1"));
+        assert_eq!(attrs.get("role"), Some(&Attr::Role(Role::Tab)));
+        assert_eq!(attrs.get("aria-selected"), Some(&Attr::AriaSelected(false)));
+        assert_eq!(
+            attrs.get("data-state"),
+            Some(&Attr::DataState(DataState::Inactive))
+        );
+        assert_eq!(attrs.get("tabindex"), Some(&Attr::TabIndex(-1)));
+    }
+
+    #[test]
+    fn tabs_trigger_attrs_skip_disabled_active_tab() {
+        let mut model = TabsModel::new(3);
+        assert_eq!(model.selected(), Some(0));
+        model.set_disabled(&0, true);
+        let active = tabs_trigger_attrs(&model, 0, None, None);
+        assert_eq!(active.get("tabindex"), Some(&Attr::TabIndex(-1)));
+        assert_eq!(active.get("aria-disabled"), Some(&Attr::AriaDisabled(true)));
+        assert_eq!(
+            tabs_trigger_attrs(&model, 1, None, None).get("tabindex"),
+            Some(&Attr::TabIndex(0))
+        );
+    }
 }
@@ -1,34 +1,581 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::keyboard::KeyInput;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TypeaheadMatchKind {
+    Subsequence,
+    WordBoundary,
+    Prefix,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeaheadScore {
+    pub kind: TypeaheadMatchKind,
+    pub score: u32,
+    pub ranges: Vec<Range<usize>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeaheadMatch {
+    pub index: usize,
+    pub kind: TypeaheadMatchKind,
+    pub score: u32,
+    pub ranges: Vec<Range<usize>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeaheadOptions {
+    pub ignore_diacritics: bool,
+}
+
+impl Default for TypeaheadOptions {
+    fn default() -> Self {
+        Self::ignoring_diacritics()
+    }
+}
+
+impl TypeaheadOptions {
+    pub fn ignoring_diacritics() -> Self {
+        Self {
+            ignore_diacritics: true,
+        }
+    }
+
+    pub fn matching_diacritics() -> Self {
+        Self {
+            ignore_diacritics: false,
+        }
+    }
+}
+
+pub const TYPEAHEAD_TIMEOUT_MS: u64 = 500;
+
+#[derive(Debug, Clone)]
+pub struct Typeahead {
+    buffer: String,
+    last_input: Option<u64>,
+    timeout: u64,
+    options: TypeaheadOptions,
+    folded: Vec<(String, String)>,
+}
+
+impl PartialEq for Typeahead {
+    fn eq(&self, other: &Self) -> bool {
+        self.buffer == other.buffer
+            && self.last_input == other.last_input
+            && self.timeout == other.timeout
+            && self.options == other.options
+    }
+}
+
+impl Eq for Typeahead {}
+
+impl Default for Typeahead {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Typeahead {
+    pub fn new() -> Self {
+        Self::with_timeout(TYPEAHEAD_TIMEOUT_MS)
+    }
+
+    pub fn with_timeout(timeout: u64) -> Self {
+        Self {
+            buffer: String::new(),
+            last_input: None,
+            timeout,
+            options: TypeaheadOptions::default(),
+            folded: Vec::new(),
+        }
+    }
+
+    pub fn options(&self) -> TypeaheadOptions {
+        self.options
+    }
+
+    pub fn set_options(&mut self, options: TypeaheadOptions) {
+        if self.options != options {
+            self.folded.clear();
+        }
+        self.options = options;
+    }
+
+    pub fn timeout(&self) -> u64 {
+        self.timeout
+    }
+
+    pub fn set_timeout(&mut self, timeout: u64) {
+        self.timeout = timeout;
+    }
+
+    pub fn buffer(&self) -> &str {
+        &self.buffer
+    }
+
+    pub fn is_active(&self, now: u64) -> bool {
+        !self.buffer.is_empty() && !self.expired(now)
+    }
+
+    pub fn expired(&self, now: u64) -> bool {
+        match self.last_input {
+            Some(last) => now.saturating_sub(last) >= self.timeout,
+            None => true,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+        self.last_input = None;
+    }
+
+    pub fn push(&mut self, ch: char, now: u64) -> bool {
+        if self.expired(now) {
+            self.buffer.clear();
+        }
+        if ch == ' ' && self.buffer.is_empty() {
+            return false;
+        }
+        self.buffer.push(ch);
+        self.last_input = Some(now);
+        true
+    }
+
+    pub fn type_char<T>(
+        &mut self,
+        ch: char,
+        now: u64,
+        items: &[T],
+        active: Option<usize>,
+        label: impl Fn(&T) -> &str,
+        disabled: impl Fn(&T) -> bool,
+    ) -> Option<usize> {
+        if !self.push(ch, now) {
+            return None;
+        }
+        self.search(items, active, label, disabled)
+    }
+
+    pub fn search<T>(
+        &mut self,
+        items: &[T],
+        active: Option<usize>,
+        label: impl Fn(&T) -> &str,
+        disabled: impl Fn(&T) -> bool,
+    ) -> Option<usize> {
+        let len = items.len();
+        if len == 0 || self.buffer.is_empty() {
+            return None;
+        }
+
+        let buffer = typeahead_fold(&self.buffer, self.options);
+        let clusters = typeahead_clusters(&buffer, self.options);
+        let first = *clusters.first()?;
+        let repeated = clusters
+            .iter()
+            .all(|cluster| clusters_eq(cluster, &first, self.options));
+        let query = if repeated {
+            &buffer[first.start..first.end]
+        } else {
+            &buffer[..]
+        };
+
+        let start = match active {
+            Some(index) if repeated => (index + 1) % len,
+            Some(index) => index.min(len - 1),
+            None => 0,
+        };
+
+        self.folded.resize_with(len, Default::default);
+        let options = self.options;
+        let folded = &mut self.folded;
+        (0..len).map(|offset| (start + offset) % len).find(|index| {
+            let item = &items[*index];
+            if disabled(item) {
+                return false;
+            }
+            let text = label(item);
+            let (cached, value) = &mut folded[*index];
+            if cached != text {
+                *cached = text.into();
+                *value = typeahead_fold(text, options);
+            }
+            typeahead_folded_starts_with(value, query)
+        })
+    }
+}
+
+pub fn typeahead_key_char(input: impl Into<KeyInput>) -> Option<char> {
+    let input = input.into();
+    if input.modifiers.has_command() || input.is_composing {
+        return None;
+    }
+    input.key.char()
+}
+
 pub fn typeahead_match<T>(
     items: &[T],
     query: &str,
     label: impl Fn(&T) -> &str,
+) -> Option<usize> {
+    typeahead_match_with_options(items, query, TypeaheadOptions::default(), label)
+}
+
+pub fn typeahead_match_with_options<T>(
+    items: &[T],
+    query: &str,
+    options: TypeaheadOptions,
+    label: impl Fn(&T) -> &str,
 ) -> Option<usize> {
     if query.is_empty() {
         return None;
     }
 
+    let query = typeahead_clusters(query, options);
     items.iter().position(|item| {
-        let value = label(item);
-        starts_with_ignore_ascii_case(value, query)
+        let value = typeahead_clusters(label(item), options);
+        clusters_match_at(&value, 0, &query, options).is_some()
     })
 }
 
-fn starts_with_ignore_ascii_case(value: &str, query: &str) -> bool {
-    let mut value_bytes = value.bytes();
-    for query_byte in query.bytes() {
-        let Some(value_byte) = value_bytes.next() else {
-            return false;
+pub fn typeahead_matches<T>(
+    items: &[T],
+    query: &str,
+    label: impl Fn(&T) -> &str,
+) -> Vec<TypeaheadMatch> {
+    typeahead_matches_with_options(items, query, TypeaheadOptions::default(), label)
+}
+
+pub fn typeahead_matches_with_options<T>(
+    items: &[T],
+    query: &str,
+    options: TypeaheadOptions,
+    label: impl Fn(&T) -> &str,
+) -> Vec<TypeaheadMatch> {
+    let mut matches: Vec<TypeaheadMatch> = items
+        .iter()
+        .enumerate()
+        .filter_map(|(index, item)| {
+            typeahead_score_with_options(label(item), query, options).map(|score| {
+                TypeaheadMatch {
+                    index,
+                    kind: score.kind,
+                    score: score.score,
+                    ranges: score.ranges,
+                }
+            })
+        })
+        .collect();
+    matches.sort_by(|a, b| b.score.cmp(&a.score).then(a.index.cmp(&b.index)));
+    matches
+}
+
+pub fn typeahead_score(value: &str, query: &str) -> Option<TypeaheadScore> {
+    typeahead_score_with_options(value, query, TypeaheadOptions::default())
+}
+
+pub fn typeahead_score_with_options(
+    value: &str,
+    query: &str,
+    options: TypeaheadOptions,
+) -> Option<TypeaheadScore> {
+    if query.is_empty() {
+        return None;
+    }
+
+    let value_clusters = typeahead_clusters(value, options);
+    let query_clusters = typeahead_clusters(query, options);
+    if query_clusters.is_empty() {
+        return None;
+    }
+
+    if let Some(end) = clusters_match_at(&value_clusters, 0, &query_clusters, options) {
+        let slack = value.len().saturating_sub(end).min(999) as u32;
+        return Some(TypeaheadScore {
+            kind: TypeaheadMatchKind::Prefix,
+            score: 3_000 + 999 - slack,
+            ranges: alloc::vec![0..end],
+        });
+    }
+
+    let mut previous: Option<char> = None;
+    for (index, cluster) in value_clusters.iter().enumerate() {
+        if is_word_start(previous, cluster.original)
+            && let Some(end) = clusters_match_at(&value_clusters, index, &query_clusters, options)
+        {
+            let offset = cluster.start.min(999) as u32;
+            return Some(TypeaheadScore {
+                kind: TypeaheadMatchKind::WordBoundary,
+                score: 2_000 + 999 - offset,
+                ranges: alloc::vec![cluster.start..end],
+            });
+        }
+        previous = Some(cluster.original);
+    }
+
+    subsequence_score(&value_clusters, &query_clusters, options)
+}
+
+pub fn typeahead_fold(value: &str, options: TypeaheadOptions) -> String {
+    let mut folded = String::with_capacity(value.len());
+    for cluster in typeahead_clusters(value, options) {
+        folded.push(cluster.base);
+        if !options.ignore_diacritics {
+            folded.push_str(cluster.marks);
+        }
+    }
+    folded
+}
+
+pub fn typeahead_fold_char(ch: char) -> char {
+    if let Ok(index) = CASE_FOLD_TABLE.binary_search_by_key(&ch, |(from, _)| *from) {
+        return CASE_FOLD_TABLE[index].1;
+    }
+    match ch as u32 {
+        0x13a0..=0x13f5 => ch,
+        0x13f8..=0x13fd => char::from_u32(ch as u32 - 8).unwrap_or(ch),
+        0xab70..=0xabbf => char::from_u32(ch as u32 - 0xab70 + 0x13a0).unwrap_or(ch),
+        _ => {
+            let mut lower = ch.to_lowercase();
+            match (lower.next(), lower.next()) {
+                (Some(folded), None) => folded,
+                _ => ch,
+            }
+        }
+    }
+}
+
+pub fn typeahead_strip_diacritic(ch: char) -> char {
+    if (ch as u32) < 0xc0 {
+        return ch;
+    }
+    DIACRITIC_TABLE
+        .binary_search_by_key(&ch, |(variant, _)| *variant)
+        .map(|index| DIACRITIC_TABLE[index].1)
+        .unwrap_or(ch)
+}
+
+const CASE_FOLD_TABLE: &[(char, char)] = &[
+    ('\u{b5}', '\u{3bc}'),
+    ('\u{17f}', 's'),
+    ('\u{3c2}', '\u{3c3}'),
+    ('\u{3d0}', '\u{3b2}'),
+    ('\u{3d1}', '\u{3b8}'),
+    ('\u{3d5}', '\u{3c6}'),
+    ('\u{3d6}', '\u{3c0}'),
+    ('\u{3f0}', '\u{3ba}'),
+    ('\u{3f1}', '\u{3c1}'),
+    ('\u{3f5}', '\u{3b5}'),
+    ('\u{1c80}', '\u{432}'),
+    ('\u{1c81}', '\u{434}'),
+    ('\u{1c82}', '\u{43e}'),
+    ('\u{1c83}', '\u{441}'),
+    ('\u{1c84}', '\u{442}'),
+    ('\u{1c85}', '\u{442}'),
+    ('\u{1c86}', '\u{44a}'),
+    ('\u{1c87}', '\u{463}'),
+    ('\u{1c88}', '\u{a64b}'),
+    ('\u{1e9b}', '\u{1e61}'),
+    ('\u{1e9e}', '\u{df}'),
+    ('\u{1fbe}', '\u{3b9}'),
+    ('\u{1fd3}', '\u{390}'),
+    ('\u{1fe3}', '\u{3b0}'),
+    ('\u{fb05}', '\u{fb06}'),
+];
+
+const DIACRITIC_TABLE: &[(char, char)] = &[
+    ('à', 'a'), ('á', 'a'), ('â', 'a'), ('ã', 'a'), ('ä', 'a'), ('å', 'a'),
+    ('ç', 'c'), ('è', 'e'), ('é', 'e'), ('ê', 'e'), ('ë', 'e'), ('ì', 'i'),
+    ('í', 'i'), ('î', 'i'), ('ï', 'i'), ('ñ', 'n'), ('ò', 'o'), ('ó', 'o'),
+    ('ô', 'o'), ('õ', 'o'), ('ö', 'o'), ('ø', 'o'), ('ù', 'u'), ('ú', 'u'),
+    ('û', 'u'), ('ü', 'u'), ('ý', 'y'), ('ÿ', 'y'), ('ā', 'a'), ('ă', 'a'),
+    ('ą', 'a'), ('ć', 'c'), ('ĉ', 'c'), ('ċ', 'c'), ('č', 'c'), ('ď', 'd'),
+    ('đ', 'd'), ('ē', 'e'), ('ĕ', 'e'), ('ė', 'e'), ('ę', 'e'), ('ě', 'e'),
+    ('ĝ', 'g'), ('ğ', 'g'), ('ġ', 'g'), ('ģ', 'g'), ('ĥ', 'h'), ('ħ', 'h'),
+    ('ĩ', 'i'), ('ī', 'i'), ('ĭ', 'i'), ('į', 'i'), ('ı', 'i'), ('ĵ', 'j'),
+    ('ķ', 'k'), ('ĺ', 'l'), ('ļ', 'l'), ('ľ', 'l'), ('ŀ', 'l'), ('ł', 'l'),
+    ('ń', 'n'), ('ņ', 'n'), ('ň', 'n'), ('ō', 'o'), ('ŏ', 'o'), ('ő', 'o'),
+    ('ŕ', 'r'), ('ŗ', 'r'), ('ř', 'r'), ('ś', 's'), ('ŝ', 's'), ('ş', 's'),
+    ('š', 's'), ('ţ', 't'), ('ť', 't'), ('ŧ', 't'), ('ũ', 'u'), ('ū', 'u'),
+    ('ŭ', 'u'), ('ů', 'u'), ('ű', 'u'), ('ų', 'u'), ('ŵ', 'w'), ('ŷ', 'y'),
+    ('ź', 'z'), ('ż', 'z'), ('ž', 'z'), ('ơ', 'o'), ('ư', 'u'), ('ǎ', 'a'),
+    ('ǐ', 'i'), ('ǒ', 'o'), ('ǔ', 'u'), ('ǖ', 'u'), ('ǘ', 'u'), ('ǚ', 'u'),
+    ('ǜ', 'u'), ('ǟ', 'a'), ('ǡ', 'a'), ('ǧ', 'g'), ('ǩ', 'k'), ('ǫ', 'o'),
+    ('ǭ', 'o'), ('ǰ', 'j'), ('ǵ', 'g'), ('ǹ', 'n'), ('ǻ', 'a'), ('ǿ', 'o'),
+    ('ȁ', 'a'), ('ȃ', 'a'), ('ȅ', 'e'), ('ȇ', 'e'), ('ȉ', 'i'), ('ȋ', 'i'),
+    ('ȍ', 'o'), ('ȏ', 'o'), ('ȑ', 'r'), ('ȓ', 'r'), ('ȕ', 'u'), ('ȗ', 'u'),
+    ('ș', 's'), ('ț', 't'), ('ȧ', 'a'), ('ȩ', 'e'), ('ȫ', 'o'), ('ȭ', 'o'),
+    ('ȯ', 'o'), ('ȱ', 'o'), ('ȳ', 'y'), ('ḍ', 'd'), ('ḥ', 'h'), ('ḷ', 'l'),
+    ('ṅ', 'n'), ('ṇ', 'n'), ('ṛ', 'r'), ('ṣ', 's'), ('ṭ', 't'), ('ẁ', 'w'),
+    ('ẃ', 'w'), ('ẅ', 'w'), ('ẏ', 'y'), ('ẓ', 'z'), ('ạ', 'a'), ('ả', 'a'),
+    ('ấ', 'a'), ('ầ', 'a'), ('ẩ', 'a'), ('ẫ', 'a'), ('ậ', 'a'), ('ắ', 'a'),
+    ('ằ', 'a'), ('ẳ', 'a'), ('ẵ', 'a'), ('ặ', 'a'), ('ẹ', 'e'), ('ẻ', 'e'),
+    ('ẽ', 'e'), ('ế', 'e'), ('ề', 'e'), ('ể', 'e'), ('ễ', 'e'), ('ệ', 'e'),
+    ('ỉ', 'i'), ('ị', 'i'), ('ọ', 'o'), ('ỏ', 'o'), ('ố', 'o'), ('ồ', 'o'),
+    ('ổ', 'o'), ('ỗ', 'o'), ('ộ', 'o'), ('ớ', 'o'), ('ờ', 'o'), ('ở', 'o'),
+    ('ỡ', 'o'), ('ợ', 'o'), ('ụ', 'u'), ('ủ', 'u'), ('ứ', 'u'), ('ừ', 'u'),
+    ('ử', 'u'), ('ữ', 'u'), ('ự', 'u'), ('ỳ', 'y'), ('ỵ', 'y'), ('ỷ', 'y'),
+    ('ỹ', 'y'),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cluster<'a> {
+    original: char,
+    base: char,
+    marks: &'a str,
+    start: usize,
+    end: usize,
+}
+
+fn typeahead_clusters(value: &str, options: TypeaheadOptions) -> Vec<Cluster<'_>> {
+    let mut clusters: Vec<Cluster<'_>> = Vec::new();
+    let mut join_next = false;
+    for (start, ch) in value.char_indices() {
+        let end = start + ch.len_utf8();
+        let extends = is_combining_mark(ch) || is_cluster_extender(ch) || join_next;
+        join_next = ch == '\u{200d}';
+        if extends && let Some(last) = clusters.last_mut() {
+            let marks_start = last.end - last.marks.len();
+            last.end = end;
+            last.marks = &value[marks_start..end];
+            continue;
+        }
+        let folded = typeahead_fold_char(ch);
+        let base = if options.ignore_diacritics {
+            typeahead_strip_diacritic(folded)
+        } else {
+            folded
         };
-        if value_byte.to_ascii_lowercase() != query_byte.to_ascii_lowercase() {
-            return false;
+        clusters.push(Cluster {
+            original: ch,
+            base,
+            marks: &value[end..end],
+            start,
+            end,
+        });
+    }
+    clusters
+}
+
+fn is_combining_mark(ch: char) -> bool {
+    matches!(
+        ch as u32,
+        0x0300..=0x036f | 0x1ab0..=0x1aff | 0x1dc0..=0x1dff | 0x20d0..=0x20ff | 0xfe20..=0xfe2f
+    )
+}
+
+fn is_cluster_extender(ch: char) -> bool {
+    matches!(ch as u32, 0x200d | 0xfe00..=0xfe0f | 0x1f3fb..=0x1f3ff | 0xe0020..=0xe007f)
+}
+
+fn typeahead_folded_starts_with(value: &str, query: &str) -> bool {
+    let Some(rest) = value.strip_prefix(query) else {
+        return false;
+    };
+    !query.ends_with('\u{200d}')
+        && !rest
+            .chars()
+            .next()
+            .is_some_and(|ch| is_combining_mark(ch) || is_cluster_extender(ch))
+}
+
+fn clusters_eq(a: &Cluster<'_>, b: &Cluster<'_>, options: TypeaheadOptions) -> bool {
+    a.base == b.base && (options.ignore_diacritics || a.marks == b.marks)
+}
+
+fn clusters_match_at(
+    value: &[Cluster<'_>],
+    start: usize,
+    query: &[Cluster<'_>],
+    options: TypeaheadOptions,
+) -> Option<usize> {
+    let candidates = value.get(start..start + query.len())?;
+    if query.is_empty() {
+        return None;
+    }
+    candidates
+        .iter()
+        .zip(query)
+        .all(|(value, query)| clusters_eq(value, query, options))
+        .then(|| candidates[candidates.len() - 1].end)
+}
+
+fn subsequence_score(
+    value: &[Cluster<'_>],
+    query: &[Cluster<'_>],
+    options: TypeaheadOptions,
+) -> Option<TypeaheadScore> {
+    let mut query_clusters = query.iter().peekable();
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    let mut bonus = 0u32;
+    let mut previous: Option<char> = None;
+    let mut previous_matched = false;
+    for cluster in value {
+        let Some(wanted) = query_clusters.peek() else {
+            break;
+        };
+        if clusters_eq(cluster, wanted, options) {
+            if previous_matched && let Some(last) = ranges.last_mut() {
+                last.end = cluster.end;
+                bonus += 4;
+            } else {
+                ranges.push(cluster.start..cluster.end);
+            }
+            if is_word_start(previous, cluster.original) {
+                bonus += 8;
+            }
+            bonus += 1;
+            previous_matched = true;
+            query_clusters.next();
+        } else {
+            previous_matched = false;
+        }
+        previous = Some(cluster.original);
+    }
+    if query_clusters.peek().is_some() {
+        return None;
+    }
+    Some(TypeaheadScore {
+        kind: TypeaheadMatchKind::Subsequence,
+        score: 1_000 + bonus.min(999),
+        ranges,
+    })
+}
+
+fn is_word_start(previous: Option<char>, ch: char) -> bool {
+    match previous {
+        None => true,
+        Some(previous) => {
+            (!previous.is_alphanumeric() && ch.is_alphanumeric())
+                || (previous.is_lowercase() && ch.is_uppercase())
         }
     }
-    true
 }
 
 #[cfg(test)]
 mod tests {
-    use super::typeahead_match;
+    use super::{
+        typeahead_key_char,
+        typeahead_match,
+        typeahead_matches,
+        typeahead_fold,
+        typeahead_fold_char,
+        typeahead_match_with_options,
+        typeahead_score,
+        typeahead_score_with_options,
+        typeahead_strip_diacritic,
+        Typeahead,
+        TypeaheadMatchKind,
+        TypeaheadOptions,
+        CASE_FOLD_TABLE,
+        DIACRITIC_TABLE,
+    };
+    use crate::keyboard::{KeyInput, Modifiers};
+    use std::vec::Vec;
 
     #[test]
     fn typeahead_matches_prefix() {
@@ -50,4 +597,131 @@ mod tests {
         let index = typeahead_match(&items, "", |item| item);
         assert_eq!(index, None);
     }
+
+    #[test]
+    fn typeahead_score_ranks_prefix_boundary_subsequence() {
+        let items = ["Open Recent", "Reopen", "Recent Files", "Prerender"];
+        let matches = typeahead_matches(&items, "re", |item| item);
+        let order: Vec<usize> = matches.iter().map(|matched| matched.index).collect();
+        assert_eq!(order, [1, 2, 0, 3]);
+        assert_eq!(matches[0].kind, TypeaheadMatchKind::Prefix);
+        assert_eq!(matches[1].kind, TypeaheadMatchKind::Prefix);
+        assert_eq!(matches[2].kind, TypeaheadMatchKind::WordBoundary);
+        assert_eq!(matches[3].kind, TypeaheadMatchKind::Subsequence);
+    }
+
+    #[test]
+    fn typeahead_score_reports_ranges() {
+        let score = typeahead_score("New File", "nfi").expect("match");
+        assert_eq!(score.kind, TypeaheadMatchKind::Subsequence);
+        assert_eq!(score.ranges, [0..1, 4..6]);
+
+        let score = typeahead_score("saveAs", "as").expect("match");
+        assert_eq!(score.kind, TypeaheadMatchKind::WordBoundary);
+        assert_eq!(score.ranges.len(), 1);
+        assert_eq!(score.ranges[0], 4..6);
+
+        assert!(typeahead_score("Apple", "xyz").is_none());
+    }
+
+    #[test]
+    fn typeahead_buffer_accumulates_until_timeout() {
+        let items = ["Banana", "Blueberry", "Cherry"];
+        let mut typeahead = Typeahead::new();
+        let found = typeahead.type_char('b', 0, &items, Some(2), |item| item, |_| false);
+        assert_eq!(found, Some(0));
+        let found = typeahead.type_char('l', 200, &items, found, |item| item, |_| false);
+        assert_eq!(found, Some(1));
+        assert_eq!(typeahead.buffer(), "bl");
+        typeahead.type_char('c', 900, &items, found, |item| item, |_| false);
+        assert_eq!(typeahead.buffer(), "c");
+
+        let renamed = ["Banana", "Cranberry", "Blueberry"];
+        assert_eq!(typeahead.search(&renamed, None, |item| item, |_| false), Some(1));
+    }
+
+    #[test]
+    fn typeahead_repeated_key_cycles_and_skips_disabled() {
+        let items = [("Apple", false), ("Avocado", true), ("Apricot", false), ("Banana", false)];
+        let mut typeahead = Typeahead::new();
+        fn label<'a>(item: &'a (&'static str, bool)) -> &'a str {
+            item.0
+        }
+        fn disabled(item: &(&'static str, bool)) -> bool {
+            item.1
+        }
+        let first = typeahead.type_char('a', 0, &items, None, label, disabled);
+        assert_eq!(first, Some(0));
+        let second = typeahead.type_char('a', 100, &items, first, label, disabled);
+        assert_eq!(second, Some(2));
+        let third = typeahead.type_char('a', 200, &items, second, label, disabled);
+        assert_eq!(third, Some(0));
+    }
+
+    #[test]
+    fn typeahead_ignores_modifier_and_composition_keys() {
+        assert_eq!(typeahead_key_char("a"), Some('a'));
+        assert_eq!(
+            typeahead_key_char(KeyInput::from("a").with_modifiers(Modifiers::CTRL)),
+            None
+        );
+        assert_eq!(typeahead_key_char(KeyInput::from("a").with_composing(true)), None);
+        assert_eq!(typeahead_key_char("Shift"), None);
+        assert_eq!(
+            typeahead_key_char(KeyInput::from("A").with_modifiers(Modifiers::SHIFT)),
+            Some('A')
+        );
+        let mut typeahead = Typeahead::new();
+        assert!(!typeahead.push(' ', 0));
+    }
+
+    #[test]
+    fn typeahead_folds_unicode_case() {
+        let items = ["Zebra", "Élan", "Ωmega"];
+        assert_eq!(typeahead_match(&items, "é", |item| item), Some(1));
+        assert_eq!(typeahead_match(&items, "ω", |item| item), Some(2));
+        assert_eq!(typeahead_match(&items, "e", |item| item), Some(1));
+        let strict = TypeaheadOptions::matching_diacritics();
+        assert_eq!(typeahead_match_with_options(&items, "e", strict, |item| item), None);
+        assert_eq!(typeahead_fold_char('\u{1e9e}'), 'ß');
+        assert_eq!(typeahead_fold_char('\u{b5}'), typeahead_fold_char('\u{39c}'));
+        assert_eq!(typeahead_fold_char('\u{ab70}'), '\u{13a0}');
+        assert_eq!(typeahead_fold("ΣΑΣ ſ", TypeaheadOptions::default()), "σασ s");
+    }
+
+    #[test]
+    fn typeahead_ignores_diacritics_when_requested() {
+        let items = ["Apfel", "Ölfarbe", "Crème"];
+        let options = TypeaheadOptions::ignoring_diacritics();
+        assert_eq!(typeahead_match_with_options(&items, "ol", options, |item| item), Some(1));
+        assert_eq!(typeahead_match_with_options(&items, "creme", options, |item| item), Some(2));
+        let decomposed = ["Cre\u{300}me"];
+        assert_eq!(typeahead_match_with_options(&decomposed, "crè", options, |item| item), Some(0));
+
+        let mut typeahead = Typeahead::new();
+        typeahead.set_options(options);
+        let found = typeahead.type_char('o', 0, &items, None, |item| item, |_| false);
+        assert_eq!(found, Some(1));
+    }
+
+    #[test]
+    fn typeahead_ranges_never_split_clusters() {
+        let options = TypeaheadOptions::ignoring_diacritics();
+        let score = typeahead_score_with_options("Cafe\u{301} noir", "cafe", options)
+            .expect("match");
+        assert_eq!(score.ranges.len(), 1);
+        assert_eq!(score.ranges[0], 0..6);
+
+        let score = typeahead_score_with_options("Über", "ub", options).expect("match");
+        assert_eq!(score.ranges[0], 0..3);
+        assert!(typeahead_score("Cafe\u{301}", "café").is_some());
+    }
+
+    #[test]
+    fn typeahead_tables_are_sorted_for_binary_search() {
+        assert!(CASE_FOLD_TABLE.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(DIACRITIC_TABLE.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(typeahead_strip_diacritic('ẹ'), 'e');
+        assert_eq!(typeahead_strip_diacritic('ß'), 'ß');
+    }
 }
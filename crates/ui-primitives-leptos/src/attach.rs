@@ -1,6 +1,7 @@
 use leptos::html;
 use leptos::prelude::*;
 use std::collections::{BTreeMap, BTreeSet};
+use ui_primitives_core::aria::{Attr, AttrSet, AttrValue};
 
 #[cfg(target_arch = "wasm32")]
 use std::cell::RefCell;
@@ -43,6 +44,19 @@ impl PrimitiveAttribute {
     }
 }
 
+impl From<Attr> for PrimitiveAttribute {
+    fn from(attr: Attr) -> Self {
+        match attr.value() {
+            AttrValue::String(value) => PrimitiveAttribute::string(attr.name(), value),
+            AttrValue::Bool(value) => PrimitiveAttribute::bool(attr.name(), value),
+        }
+    }
+}
+
+pub fn primitive_attrs(attrs: AttrSet) -> Vec<PrimitiveAttribute> {
+    attrs.into_iter().map(PrimitiveAttribute::from).collect()
+}
+
 #[cfg(target_arch = "wasm32")]
 pub type PrimitiveEventHandler = Callback<web_sys::Event>;
 #[cfg(not(target_arch = "wasm32"))]
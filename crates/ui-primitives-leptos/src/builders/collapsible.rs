@@ -1,46 +1,18 @@
-use crate::PrimitiveAttribute;
-use ui_primitives_core::collapsible::{CollapsibleModel, CollapsibleState};
+use crate::{primitive_attrs, PrimitiveAttribute};
+use ui_primitives_core::collapsible::{self, CollapsibleModel};
 
 pub fn collapsible_trigger_attrs(
     model: &CollapsibleModel,
     controls: Option<&str>,
 ) -> Vec<PrimitiveAttribute> {
-    let mut attrs = Vec::new();
-    let state = collapsible_state_value(model.state());
-    attrs.push(PrimitiveAttribute::string("data-state", state));
-    attrs.push(PrimitiveAttribute::string(
-        "aria-expanded",
-        if model.open() { "true" } else { "false" },
-    ));
-    attrs.push(PrimitiveAttribute::bool("disabled", model.disabled()));
-    if model.disabled() {
-        attrs.push(PrimitiveAttribute::bool("data-disabled", true));
-    }
-    if let Some(controls) = controls {
-        attrs.push(PrimitiveAttribute::string("aria-controls", controls));
-    }
-    attrs
+    primitive_attrs(collapsible::collapsible_trigger_attrs(model, controls))
 }
 
 pub fn collapsible_content_attrs(
     model: &CollapsibleModel,
     content_id: Option<&str>,
 ) -> Vec<PrimitiveAttribute> {
-    let mut attrs = Vec::new();
-    let state = collapsible_state_value(model.state());
-    attrs.push(PrimitiveAttribute::string("data-state", state));
-    attrs.push(PrimitiveAttribute::bool("hidden", !model.open()));
-    if let Some(id) = content_id {
-        attrs.push(PrimitiveAttribute::string("id", id));
-    }
-    attrs
-}
-
-fn collapsible_state_value(state: CollapsibleState) -> &'static str {
-    match state {
-        CollapsibleState::Open => "open",
-        CollapsibleState::Closed => "closed",
-    }
+    primitive_attrs(collapsible::collapsible_content_attrs(model, content_id))
 }
 
 #[cfg(test)]
@@ -0,0 +1,84 @@
+use crate::{direction_provided, primitive_attrs, PrimitiveAttribute};
+use ui_primitives_core::aria::Attr;
+use ui_primitives_core::color::{self, ColorAreaModel, ColorSliderModel};
+
+pub fn color_area_attrs(model: &ColorAreaModel) -> Vec<PrimitiveAttribute> {
+    primitive_attrs(
+        color::color_area_attrs(model)
+            .with_opt(direction_provided(), Attr::Dir),
+    )
+}
+
+pub fn color_area_x_attrs(
+    model: &ColorAreaModel,
+    label: Option<&str>,
+) -> Vec<PrimitiveAttribute> {
+    primitive_attrs(
+        color::color_area_x_attrs(model, label)
+            .with_opt(direction_provided(), Attr::Dir),
+    )
+}
+
+pub fn color_area_y_attrs(
+    model: &ColorAreaModel,
+    label: Option<&str>,
+) -> Vec<PrimitiveAttribute> {
+    primitive_attrs(
+        color::color_area_y_attrs(model, label)
+            .with_opt(direction_provided(), Attr::Dir),
+    )
+}
+
+pub fn color_slider_thumb_attrs(
+    model: &ColorSliderModel,
+    label: Option<&str>,
+) -> Vec<PrimitiveAttribute> {
+    primitive_attrs(
+        color::color_slider_thumb_attrs(model, label)
+            .with_opt(direction_provided(), Attr::Dir),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{color_area_x_attrs, color_area_y_attrs, color_slider_thumb_attrs};
+    use crate::PrimitiveAttributeValue;
+    use ui_primitives_core::color::{ColorAreaModel, ColorChannel, ColorSliderModel, Hsv};
+
+    #[test]
+    fn color_area_emits_slider_pair() {
+        let model = ColorAreaModel::new(Hsv::new(210.0, 0.5, 1.0));
+        let x = color_area_x_attrs(&model, None);
+        let y = color_area_y_attrs(&model, None);
+        for attrs in [&x, &y] {
+            let role = attrs
+                .iter()
+                .find(|attr| attr.name() == "role")
+                .expect("role");
+            assert_eq!(role.value(), &PrimitiveAttributeValue::String("slider".to_string()));
+            assert!(attrs.iter().any(|attr| attr.name() == "aria-valuetext"));
+        }
+        let tabindex = y
+            .iter()
+            .find(|attr| attr.name() == "tabindex")
+            .expect("tabindex");
+        assert_eq!(tabindex.value(), &PrimitiveAttributeValue::String("-1".to_string()));
+        for name in ["tabindex", "aria-orientation"] {
+            assert_eq!(y.iter().filter(|attr| attr.name() == name).count(), 1);
+        }
+    }
+
+    #[test]
+    fn color_slider_describes_color() {
+        let model = ColorSliderModel::new(Hsv::new(120.0, 1.0, 1.0), ColorChannel::Hue);
+        let attrs = color_slider_thumb_attrs(&model, None);
+        let text = attrs
+            .iter()
+            .find(|attr| attr.name() == "aria-valuetext")
+            .expect("aria-valuetext");
+        assert_eq!(
+            text.value(),
+            &PrimitiveAttributeValue::String("Hue 120°, green".to_string())
+        );
+    }
+}
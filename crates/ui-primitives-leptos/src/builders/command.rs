@@ -0,0 +1,74 @@
+use crate::{primitive_attrs, PrimitiveAttribute};
+use ui_primitives_core::command::{self, CommandItem, CommandModel};
+
+pub use ui_primitives_core::command::command_item_id;
+
+pub fn command_input_attrs(
+    model: &CommandModel,
+    list_id: &str,
+    label: Option<&str>,
+) -> Vec<PrimitiveAttribute> {
+    primitive_attrs(command::command_input_attrs(model, list_id, label))
+}
+
+pub fn command_list_attrs(list_id: &str, label: Option<&str>) -> Vec<PrimitiveAttribute> {
+    primitive_attrs(command::command_list_attrs(list_id, label))
+}
+
+pub fn command_group_attrs(heading: Option<&str>) -> Vec<PrimitiveAttribute> {
+    primitive_attrs(command::command_group_attrs(heading))
+}
+
+pub fn command_group_heading_attrs() -> Vec<PrimitiveAttribute> {
+    primitive_attrs(command::command_group_heading_attrs())
+}
+
+pub fn command_item_attrs(
+    model: &CommandModel,
+    item: &CommandItem,
+    list_id: &str,
+) -> Vec<PrimitiveAttribute> {
+    primitive_attrs(command::command_item_attrs(model, item, list_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{command_input_attrs, command_item_attrs};
+    use crate::PrimitiveAttributeValue;
+    use ui_primitives_core::command::{CommandItem, CommandModel};
+
+    #[test]
+    fn input_points_at_highlighted_option() {
+        let model = CommandModel::new(vec![
+            CommandItem::new("copy", "Copy"),
+            CommandItem::new("paste", "Paste"),
+        ]);
+        let attrs = command_input_attrs(&model, "cmd", None);
+        let active = attrs
+            .iter()
+            .find(|attr| attr.name() == "aria-activedescendant")
+            .expect("aria-activedescendant");
+        assert_eq!(
+            active.value(),
+            &PrimitiveAttributeValue::String("cmd-copy".to_string())
+        );
+    }
+
+    #[test]
+    fn item_attrs_reflect_highlight() {
+        let model = CommandModel::new(vec![
+            CommandItem::new("copy", "Copy"),
+            CommandItem::new("paste", "Paste"),
+        ]);
+        let items = model.results();
+        let attrs = command_item_attrs(&model, items[1], "cmd");
+        let selected = attrs
+            .iter()
+            .find(|attr| attr.name() == "aria-selected")
+            .expect("aria-selected");
+        assert_eq!(
+            selected.value(),
+            &PrimitiveAttributeValue::String("false".to_string())
+        );
+    }
+}
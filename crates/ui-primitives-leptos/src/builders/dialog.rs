@@ -1,19 +1,10 @@
-use crate::PrimitiveAttribute;
-use ui_primitives_core::dialog::{DialogModel, DialogState};
+use crate::{model_send, primitive_attrs, DismissableReason, PrimitiveAttribute};
+use leptos::prelude::RwSignal;
+use ui_primitives_core::dialog::{self, DialogEvent, DialogModel};
+use ui_primitives_core::model::Outcome;
 
 pub fn dialog_trigger_attrs(model: &DialogModel, controls_id: Option<&str>) -> Vec<PrimitiveAttribute> {
-    let mut attrs = Vec::new();
-    let state = dialog_state_value(model.state());
-    attrs.push(PrimitiveAttribute::string("data-state", state));
-    attrs.push(PrimitiveAttribute::string("aria-haspopup", "dialog"));
-    attrs.push(PrimitiveAttribute::string(
-        "aria-expanded",
-        if model.open() { "true" } else { "false" },
-    ));
-    if let Some(controls) = controls_id {
-        attrs.push(PrimitiveAttribute::string("aria-controls", controls));
-    }
-    attrs
+    primitive_attrs(dialog::dialog_trigger_attrs(model, controls_id))
 }
 
 pub fn dialog_content_attrs(
@@ -21,35 +12,20 @@ pub fn dialog_content_attrs(
     labelled_by: Option<&str>,
     described_by: Option<&str>,
 ) -> Vec<PrimitiveAttribute> {
-    let mut attrs = Vec::new();
-    let state = dialog_state_value(model.state());
-    attrs.push(PrimitiveAttribute::string("data-state", state));
-    attrs.push(PrimitiveAttribute::string("role", "dialog"));
-    attrs.push(PrimitiveAttribute::string("tabindex", "-1"));
-    if model.modal() {
-        attrs.push(PrimitiveAttribute::string("aria-modal", "true"));
-    }
-    if let Some(label) = labelled_by {
-        attrs.push(PrimitiveAttribute::string("aria-labelledby", label));
-    }
-    if let Some(description) = described_by {
-        attrs.push(PrimitiveAttribute::string("aria-describedby", description));
-    }
-    attrs
+    primitive_attrs(dialog::dialog_content_attrs(model, labelled_by, described_by))
 }
 
-fn dialog_state_value(state: DialogState) -> &'static str {
-    match state {
-        DialogState::Open => "open",
-        DialogState::Closed => "closed",
-    }
+pub fn dialog_send_dismiss(model: RwSignal<DialogModel>, reason: DismissableReason) -> Outcome {
+    model_send(model, DialogEvent::from(reason))
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{dialog_content_attrs, dialog_trigger_attrs};
-    use crate::PrimitiveAttributeValue;
+    use super::{dialog_content_attrs, dialog_send_dismiss, dialog_trigger_attrs};
+    use crate::{DismissableReason, PrimitiveAttributeValue};
+    use leptos::prelude::*;
     use ui_primitives_core::dialog::DialogModel;
+    use ui_primitives_core::model::Effect;
 
     #[test]
     fn trigger_attrs_reflect_open() {
@@ -72,4 +48,18 @@ mod tests {
         assert!(attrs.iter().any(|attr| attr.name() == "role"));
         assert!(attrs.iter().any(|attr| attr.name() == "aria-modal"));
     }
+
+    #[test]
+    fn send_dismiss_closes_through_model() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let model = RwSignal::new(DialogModel::new(true));
+            assert!(dialog_send_dismiss(model, DismissableReason::FocusOutside).is_empty());
+            assert_eq!(
+                dialog_send_dismiss(model, DismissableReason::Escape).effects(),
+                [Effect::PreventDefault, Effect::OpenChanged(false)]
+            );
+            assert!(!model.get_untracked().open());
+        });
+    }
 }
@@ -1,7 +1,32 @@
 pub mod collapsible;
+pub mod color;
+pub mod command;
 pub mod dialog;
+pub mod sheet;
+pub mod slider;
 pub mod tabs;
 
 pub use collapsible::{collapsible_content_attrs, collapsible_trigger_attrs};
-pub use dialog::{dialog_content_attrs, dialog_trigger_attrs};
-pub use tabs::{tabs_list_attrs, tabs_panel_attrs, tabs_trigger_attrs};
+pub use color::{
+    color_area_attrs,
+    color_area_x_attrs,
+    color_area_y_attrs,
+    color_slider_thumb_attrs,
+};
+pub use command::{
+    command_group_attrs,
+    command_group_heading_attrs,
+    command_input_attrs,
+    command_item_attrs,
+    command_item_id,
+    command_list_attrs,
+};
+pub use dialog::{dialog_content_attrs, dialog_send_dismiss, dialog_trigger_attrs};
+pub use sheet::{sheet_background_attrs, sheet_content_attrs, sheet_style_vars};
+pub use slider::slider_thumb_attrs;
+pub use tabs::{
+    tabs_list_attrs,
+    tabs_panel_attrs,
+    tabs_send_key,
+    tabs_trigger_attrs,
+};
@@ -0,0 +1,41 @@
+use crate::{primitive_attrs, PrimitiveAttribute};
+use ui_primitives_core::sheet::{self, SheetModel};
+
+pub use ui_primitives_core::sheet::sheet_style_vars;
+
+pub fn sheet_content_attrs(
+    model: &SheetModel,
+    labelled_by: Option<&str>,
+    described_by: Option<&str>,
+) -> Vec<PrimitiveAttribute> {
+    primitive_attrs(sheet::sheet_content_attrs(model, labelled_by, described_by))
+}
+
+pub fn sheet_background_attrs(model: &SheetModel) -> Vec<PrimitiveAttribute> {
+    primitive_attrs(sheet::sheet_background_attrs(model))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sheet_content_attrs, sheet_style_vars};
+    use ui_primitives_core::sheet::SheetModel;
+
+    #[test]
+    fn content_attrs_reuse_dialog_semantics() {
+        let model = SheetModel::new(true);
+        let attrs = sheet_content_attrs(&model, Some("title"), None);
+        assert!(attrs.iter().any(|attr| attr.name() == "role"));
+        assert!(attrs.iter().any(|attr| attr.name() == "aria-modal"));
+        assert!(attrs.iter().any(|attr| attr.name() == "style"));
+    }
+
+    #[test]
+    fn style_vars_expose_drag_offset() {
+        let mut model = SheetModel::new(true).with_viewport(600.0);
+        model.drag_start(0.0, 0.0);
+        model.drag_move(40.0, 100.0);
+        let style = sheet_style_vars(&model);
+        assert!(style.contains("--sheet-drag-offset: 40px"));
+        assert!(style.contains("--sheet-height: 560px"));
+    }
+}
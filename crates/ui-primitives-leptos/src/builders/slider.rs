@@ -0,0 +1,51 @@
+use crate::{direction_provided, primitive_attrs, PrimitiveAttribute};
+use ui_primitives_core::aria::Attr;
+use ui_primitives_core::slider::{self, SliderModel};
+
+pub fn slider_thumb_attrs(
+    model: &SliderModel,
+    label: Option<&str>,
+    value_text: Option<&str>,
+) -> Vec<PrimitiveAttribute> {
+    primitive_attrs(
+        slider::slider_thumb_attrs(model, label, value_text)
+            .with_opt(direction_provided(), Attr::Dir),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::slider_thumb_attrs;
+    use crate::{provide_direction, PrimitiveAttributeValue};
+    use leptos::prelude::Owner;
+    use ui_primitives_core::direction::Direction;
+    use ui_primitives_core::slider::SliderModel;
+
+    #[test]
+    fn thumb_attrs_include_range() {
+        let model = SliderModel::new(25.0, 0.0, 100.0);
+        let attrs = slider_thumb_attrs(&model, Some("Volume"), None);
+        let now = attrs
+            .iter()
+            .find(|attr| attr.name() == "aria-valuenow")
+            .expect("aria-valuenow");
+        assert_eq!(now.value(), &PrimitiveAttributeValue::String("25".to_string()));
+        assert!(attrs.iter().any(|attr| attr.name() == "aria-label"));
+        assert!(!attrs.iter().any(|attr| attr.name() == "dir"));
+    }
+
+    #[test]
+    fn thumb_attrs_follow_provided_direction() {
+        let owner = Owner::new();
+        owner.with(|| {
+            provide_direction(Direction::Rtl);
+            let model = SliderModel::new(25.0, 0.0, 100.0);
+            let attrs = slider_thumb_attrs(&model, None, None);
+            let dir = attrs
+                .iter()
+                .find(|attr| attr.name() == "dir")
+                .expect("dir");
+            assert_eq!(dir.value(), &PrimitiveAttributeValue::String("rtl".to_string()));
+        });
+    }
+}
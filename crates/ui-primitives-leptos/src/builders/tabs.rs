@@ -1,92 +1,59 @@
-use crate::PrimitiveAttribute;
+use crate::{direction_provided, model_send_key, primitive_attrs, PrimitiveAttribute};
+use leptos::ev::KeyboardEvent;
+use leptos::prelude::RwSignal;
+use ui_primitives_core::model::Outcome;
 use ui_primitives_core::orientation::Orientation;
-use ui_primitives_core::tabs::TabsModel;
+use ui_primitives_core::tabs::{self, TabsEvent, TabsModel};
 
 pub fn tabs_list_attrs(orientation: Orientation) -> Vec<PrimitiveAttribute> {
-    let mut attrs = Vec::new();
-    attrs.push(PrimitiveAttribute::string("role", "tablist"));
-    if orientation == Orientation::Vertical {
-        attrs.push(PrimitiveAttribute::string(
-            "aria-orientation",
-            orientation.as_aria_value(),
-        ));
-    }
-    attrs
+    primitive_attrs(tabs::tabs_list_attrs(orientation, direction_provided()))
 }
 
-pub fn tabs_trigger_attrs(
-    model: &TabsModel,
+pub fn tabs_trigger_attrs<K: PartialEq + Clone>(
+    model: &TabsModel<K>,
     index: usize,
     trigger_id: Option<&str>,
     controls_id: Option<&str>,
-    disabled: bool,
 ) -> Vec<PrimitiveAttribute> {
-    let mut attrs = Vec::new();
-    let selected = model.selected() == Some(index);
-    let focused = model.focused() == Some(index);
-    attrs.push(PrimitiveAttribute::string("role", "tab"));
-    attrs.push(PrimitiveAttribute::string(
-        "aria-selected",
-        if selected { "true" } else { "false" },
-    ));
-    attrs.push(PrimitiveAttribute::string(
-        "data-state",
-        if selected { "active" } else { "inactive" },
-    ));
-    attrs.push(PrimitiveAttribute::string(
-        "tabindex",
-        if focused && !disabled { "0" } else { "-1" },
-    ));
-    attrs.push(PrimitiveAttribute::bool("disabled", disabled));
-    if disabled {
-        attrs.push(PrimitiveAttribute::string("aria-disabled", "true"));
-    }
-    if let Some(id) = trigger_id {
-        attrs.push(PrimitiveAttribute::string("id", id));
-    }
-    if let Some(controls) = controls_id {
-        attrs.push(PrimitiveAttribute::string("aria-controls", controls));
-    }
-    attrs
+    primitive_attrs(tabs::tabs_trigger_attrs(model, index, trigger_id, controls_id))
 }
 
-pub fn tabs_panel_attrs(
-    model: &TabsModel,
+pub fn tabs_send_key<K>(model: RwSignal<TabsModel<K>>, event: &KeyboardEvent) -> Outcome<K>
+where
+    K: PartialEq + Clone + Send + Sync + 'static,
+{
+    model_send_key(model, event, |input, now| TabsEvent::Key { input, now })
+}
+
+pub fn tabs_panel_attrs<K: PartialEq + Clone>(
+    model: &TabsModel<K>,
     index: usize,
     panel_id: Option<&str>,
     labelled_by: Option<&str>,
 ) -> Vec<PrimitiveAttribute> {
-    let mut attrs = Vec::new();
-    let selected = model.selected() == Some(index);
-    attrs.push(PrimitiveAttribute::string("role", "tabpanel"));
-    attrs.push(PrimitiveAttribute::bool("hidden", !selected));
-    attrs.push(PrimitiveAttribute::string("tabindex", "0"));
-    if let Some(id) = panel_id {
-        attrs.push(PrimitiveAttribute::string("id", id));
-    }
-    if let Some(labelled_by) = labelled_by {
-        attrs.push(PrimitiveAttribute::string("aria-labelledby", labelled_by));
-    }
-    attrs
+    primitive_attrs(tabs::tabs_panel_attrs(model, index, panel_id, labelled_by))
 }
 
 #[cfg(test)]
 mod tests {
     use super::{tabs_list_attrs, tabs_panel_attrs, tabs_trigger_attrs};
-    use crate::PrimitiveAttributeValue;
+    use crate::{model_send, PrimitiveAttributeValue};
+    use leptos::prelude::*;
+    use ui_primitives_core::model::Effect;
     use ui_primitives_core::orientation::Orientation;
-    use ui_primitives_core::tabs::TabsModel;
+    use ui_primitives_core::tabs::{TabsEvent, TabsModel};
 
     #[test]
     fn list_attrs_include_orientation_for_vertical() {
         let attrs = tabs_list_attrs(Orientation::Vertical);
         assert!(attrs.iter().any(|attr| attr.name() == "aria-orientation"));
+        assert!(!attrs.iter().any(|attr| attr.name() == "dir"));
     }
 
     #[test]
     fn trigger_attrs_selected_state() {
         let model = TabsModel::new(2);
-        let attrs = tabs_trigger_attrs(&model, 0, None, None, false);
+        let attrs = tabs_trigger_attrs(&model, 0, None, None);
         let state = attrs
             .iter()
             .find(|attr| attr.name() == "data-state")
@@ -107,4 +74,33 @@ mod tests {
             .expect("hidden");
         assert_eq!(hidden.value(), &PrimitiveAttributeValue::Bool(true));
     }
+
+    #[test]
+    fn trigger_attrs_move_tab_stop_past_disabled_tab() {
+        let mut model = TabsModel::new(2);
+        model.set_disabled(&0, true);
+        let tabindex = |index| {
+            tabs_trigger_attrs(&model, index, None, None)
+                .into_iter()
+                .find(|attr| attr.name() == "tabindex")
+                .expect("tabindex")
+                .value()
+                .clone()
+        };
+        assert_eq!(tabindex(0), PrimitiveAttributeValue::String("-1".to_string()));
+        assert_eq!(tabindex(1), PrimitiveAttributeValue::String("0".to_string()));
+    }
+
+    #[test]
+    fn tabs_model_lives_in_a_plain_signal() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let model = RwSignal::new(TabsModel::new(3));
+            assert_eq!(
+                model_send(model, TabsEvent::Select(2)).effects(),
+                [Effect::FocusItem(2), Effect::SelectionChanged(Some(2))]
+            );
+            assert_eq!(model.get_untracked().selected(), Some(2));
+        });
+    }
 }
@@ -0,0 +1,79 @@
+use leptos::prelude::*;
+use ui_primitives_core::controlled::{Change, ControlModeSwitch, ControllableState};
+
+pub fn controllable_switch_warning(switch: Option<ControlModeSwitch>) -> Option<&'static str> {
+    if cfg!(debug_assertions) {
+        switch.map(|switch| switch.message())
+    } else {
+        None
+    }
+}
+
+pub fn use_controllable_state<T>(
+    prop: MaybeProp<T>,
+    default: T,
+    on_change: Option<Callback<T>>,
+) -> (Signal<T>, Callback<T>)
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let mut initial = ControllableState::new(prop.get_untracked(), default);
+    let changes: StoredValue<Vec<T>, LocalStorage> = StoredValue::new_local(Vec::new());
+    if on_change.is_some() {
+        initial.set_on_change(Some(Box::new(move |change: Change<'_, T>| {
+            changes.update_value(|changes| changes.push(change.next.clone()));
+        })));
+    }
+    let state = RwSignal::new_local(initial);
+
+    Effect::new(move || {
+        let next = prop.get();
+        let switch = state
+            .try_update_untracked(|state| state.sync(next))
+            .flatten();
+        if let Some(message) = controllable_switch_warning(switch) {
+            leptos::logging::warn!("{message}");
+        }
+        if switch.is_some() {
+            state.notify();
+        }
+    });
+
+    let value = Signal::derive(move || match prop.get() {
+        Some(value) => value,
+        None => state.with(|state| state.value().clone()),
+    });
+    let set_value = Callback::new(move |next: T| {
+        state.update(|state| {
+            state.set_if_changed(next);
+        });
+        let changed = changes
+            .try_update_value(std::mem::take)
+            .unwrap_or_default();
+        if let Some(on_change) = on_change {
+            for next in changed {
+                on_change.run(next);
+            }
+        }
+    });
+    (value, set_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::controllable_switch_warning;
+    use ui_primitives_core::controlled::{ControlMode, ControlModeSwitch};
+
+    #[test]
+    fn switch_warning_only_in_debug_builds() {
+        let switch = ControlModeSwitch {
+            from: ControlMode::Controlled,
+            to: ControlMode::Uncontrolled,
+        };
+        assert_eq!(
+            controllable_switch_warning(Some(switch)).is_some(),
+            cfg!(debug_assertions)
+        );
+        assert_eq!(controllable_switch_warning(None), None);
+    }
+}
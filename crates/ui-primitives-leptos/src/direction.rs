@@ -0,0 +1,161 @@
+use leptos::prelude::*;
+use ui_primitives_core::direction::{Direction, Directional};
+
+#[derive(Debug, Clone, Copy)]
+struct DirectionContext(Signal<Direction>);
+
+#[cfg(target_arch = "wasm32")]
+thread_local! {
+    static DOCUMENT_DIRECTION: ArcRwSignal<Direction> = direction_observe_document();
+}
+
+pub fn direction_from_document() -> Option<Direction> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.document_element())
+            .and_then(|element| element.get_attribute("dir"))
+            .and_then(|value| Direction::parse(&value))
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn direction_observe_document() -> ArcRwSignal<Direction> {
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::JsCast;
+
+    let direction = ArcRwSignal::new(direction_from_document().unwrap_or_default());
+    let signal = direction.clone();
+    let callback = Closure::<dyn FnMut()>::new(move || {
+        let next = direction_from_document().unwrap_or_default();
+        if signal.get_untracked() != next {
+            signal.set(next);
+        }
+    });
+    let root = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.document_element());
+    if let (Some(root), Ok(observer)) = (
+        root,
+        web_sys::MutationObserver::new(callback.as_ref().unchecked_ref()),
+    ) {
+        let options = web_sys::MutationObserverInit::new();
+        options.set_attributes(true);
+        options.set_attribute_filter(&js_sys::Array::of1(&"dir".into()));
+        let _ = observer.observe_with_options(&root, &options);
+        callback.forget();
+    }
+    direction
+}
+
+fn direction_document_tracked() -> Direction {
+    #[cfg(target_arch = "wasm32")]
+    {
+        DOCUMENT_DIRECTION.with(|signal| signal.get())
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Direction::Ltr
+    }
+}
+
+pub fn set_document_direction(direction: Direction) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(root) = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.document_element())
+        {
+            let _ = root.set_attribute("dir", direction.as_attr_value());
+        }
+        DOCUMENT_DIRECTION.with(|signal| {
+            if signal.get_untracked() != direction {
+                signal.set(direction);
+            }
+        });
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = direction;
+    }
+}
+
+pub fn provide_direction(direction: impl Into<Signal<Direction>>) -> Signal<Direction> {
+    let direction = direction.into();
+    provide_context(DirectionContext(direction));
+    direction
+}
+
+pub fn use_provided_direction() -> Option<Signal<Direction>> {
+    use_context::<DirectionContext>().map(|DirectionContext(direction)| direction)
+}
+
+pub fn use_direction() -> Signal<Direction> {
+    use_provided_direction()
+        .unwrap_or_else(|| Signal::derive(direction_document_tracked))
+}
+
+pub fn direction_current() -> Direction {
+    use_direction().get()
+}
+
+pub fn direction_provided() -> Option<Direction> {
+    use_provided_direction().map(|direction| direction.get())
+}
+
+pub fn use_direction_model<M, S>(model: RwSignal<M, S>)
+where
+    M: Directional + 'static,
+    S: Storage<ArcRwSignal<M>>,
+{
+    let direction = use_direction();
+    Effect::new(move || {
+        let next = direction.get();
+        if model.with_untracked(|model| model.direction()) != next {
+            model.update(|model| model.set_direction(next));
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        direction_current,
+        direction_provided,
+        provide_direction,
+        set_document_direction,
+    };
+    use leptos::prelude::Owner;
+    use ui_primitives_core::direction::Direction;
+
+    #[test]
+    fn direction_reads_nearest_provider() {
+        let root = Owner::new();
+        root.with(|| {
+            assert_eq!(direction_current(), Direction::Ltr);
+            assert_eq!(direction_provided(), None);
+            provide_direction(Direction::Rtl);
+            let child = Owner::current().expect("owner").child();
+            child.with(|| {
+                assert_eq!(direction_current(), Direction::Rtl);
+                assert_eq!(direction_provided(), Some(Direction::Rtl));
+            });
+        });
+    }
+
+    #[test]
+    fn direction_fallback_is_ltr_without_a_document() {
+        let root = Owner::new();
+        root.with(|| {
+            set_document_direction(Direction::Rtl);
+            assert_eq!(direction_current(), Direction::Ltr);
+        });
+        let next = Owner::new();
+        next.with(|| assert_eq!(direction_current(), Direction::Ltr));
+    }
+}
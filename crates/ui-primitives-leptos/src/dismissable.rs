@@ -1,6 +1,10 @@
 use leptos::ev::{FocusEvent, KeyboardEvent, PointerEvent};
 use leptos::html;
 use leptos::prelude::*;
+use ui_primitives_core::dialog::DialogEvent;
+use ui_primitives_core::keyboard::{Key, KeyInput};
+
+use crate::keyboard::key_input_from_event;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DismissableReason {
@@ -9,8 +13,18 @@ pub enum DismissableReason {
     FocusOutside,
 }
 
-pub fn dismissable_is_escape(key: &str) -> bool {
-    key == "Escape"
+impl From<DismissableReason> for DialogEvent {
+    fn from(reason: DismissableReason) -> Self {
+        match reason {
+            DismissableReason::Escape => DialogEvent::Key(KeyInput::from(Key::Escape)),
+            DismissableReason::PointerDownOutside => DialogEvent::PointerDownOutside,
+            DismissableReason::FocusOutside => DialogEvent::FocusOutside,
+        }
+    }
+}
+
+pub fn dismissable_is_escape(input: impl Into<KeyInput>) -> bool {
+    input.into().is(&Key::Escape)
 }
 
 pub fn dismissable_is_outside(is_inside: bool) -> bool {
@@ -29,7 +43,7 @@ pub fn DismissableLayer(
     let node_ref = NodeRef::<html::Div>::new();
 
     let on_keydown = move |event: KeyboardEvent| {
-        if !dismissable_is_escape(&event.key()) {
+        if !dismissable_is_escape(key_input_from_event(&event)) {
             return;
         }
         if let Some(callback) = on_escape_key_down.as_ref() {
@@ -146,7 +160,9 @@ pub fn DismissableLayer(
 
 #[cfg(test)]
 mod tests {
-    use super::{dismissable_is_escape, dismissable_is_outside};
+    use super::{dismissable_is_escape, dismissable_is_outside, DismissableReason};
+    use ui_primitives_core::dialog::{DialogEvent, DialogModel};
+    use ui_primitives_core::model::Model;
 
     #[test]
     fn dismissable_escape_match() {
@@ -159,4 +175,12 @@ mod tests {
         assert!(dismissable_is_outside(false));
         assert!(!dismissable_is_outside(true));
     }
+
+    #[test]
+    fn dismissable_reason_drives_dialog_model() {
+        let mut model = DialogModel::new(true);
+        let outcome = model.send(DialogEvent::from(DismissableReason::Escape));
+        assert!(outcome.prevents_default());
+        assert_eq!(outcome.open_changed(), Some(false));
+    }
 }
@@ -0,0 +1,93 @@
+use leptos::prelude::*;
+use ui_primitives_core::hotkey::HotkeyRegistry;
+use ui_primitives_core::keyboard::KeyInput;
+
+pub fn hotkeys_should_ignore(input: &KeyInput, editable_target: bool) -> bool {
+    editable_target && !input.modifiers.has_command() && input.key.char().is_some()
+}
+
+pub fn use_hotkeys<A>(
+    registry: RwSignal<HotkeyRegistry<A>, LocalStorage>,
+    on_action: Callback<A>,
+) where
+    A: Clone + 'static,
+{
+    #[cfg(target_arch = "wasm32")]
+    {
+        use crate::keyboard::key_input_from_event;
+        use send_wrapper::SendWrapper;
+        use wasm_bindgen::closure::Closure;
+        use wasm_bindgen::JsCast;
+
+        let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+            return;
+        };
+        let handler = Closure::wrap(Box::new(move |event: web_sys::KeyboardEvent| {
+            let input = key_input_from_event(&event);
+            let editable = event
+                .target()
+                .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+                .is_some_and(|element| {
+                    element.is_content_editable()
+                        || matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+                });
+            if hotkeys_should_ignore(&input, editable) {
+                return;
+            }
+            let now = event.time_stamp() as u64;
+            let action = registry
+                .try_update(|registry| registry.handle(&input, now).cloned())
+                .flatten();
+            if let Some(action) = action {
+                event.prevent_default();
+                on_action.run(action);
+            }
+        }) as Box<dyn FnMut(_)>);
+        let _ = document
+            .add_event_listener_with_callback("keydown", handler.as_ref().unchecked_ref());
+        let cleanup_doc = SendWrapper::new(document);
+        let cleanup_handler = SendWrapper::new(handler);
+        on_cleanup(move || {
+            let document = cleanup_doc.take();
+            let handler = cleanup_handler.take();
+            let _ = document.remove_event_listener_with_callback(
+                "keydown",
+                handler.as_ref().unchecked_ref(),
+            );
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = (registry, on_action);
+    }
+}
+
+pub fn use_hotkey_scope<A>(
+    registry: RwSignal<HotkeyRegistry<A>, LocalStorage>,
+    scope: &'static str,
+) where
+    A: 'static,
+{
+    registry.update(|registry| registry.push_scope(scope));
+    on_cleanup(move || {
+        registry.try_update(|registry| registry.pop_scope(scope));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::hotkeys_should_ignore;
+    use ui_primitives_core::keyboard::{KeyInput, Modifiers};
+
+    #[test]
+    fn typing_in_fields_does_not_trigger_hotkeys() {
+        assert!(hotkeys_should_ignore(&KeyInput::from("g"), true));
+        assert!(!hotkeys_should_ignore(&KeyInput::from("g"), false));
+        assert!(!hotkeys_should_ignore(
+            &KeyInput::from("k").with_modifiers(Modifiers::CTRL),
+            true
+        ));
+        assert!(!hotkeys_should_ignore(&KeyInput::from("Escape"), true));
+    }
+}
@@ -0,0 +1,138 @@
+use leptos::logging::warn;
+use leptos::prelude::*;
+use ui_primitives_core::ids::{IdCollision, IdRegistry, IdScope};
+
+pub const ID_DEFAULT_PREFIX: &str = "ui";
+
+#[derive(Debug, Clone)]
+struct IdScopeContext {
+    owner: Option<Owner>,
+    scope: StoredValue<IdScope>,
+    registry: StoredValue<IdRegistry>,
+}
+
+fn id_root_owner() -> Option<Owner> {
+    let mut owner = Owner::current()?;
+    while let Some(parent) = owner.parent() {
+        owner = parent;
+    }
+    Some(owner)
+}
+
+pub fn provide_id_scope(prefix: impl Into<String>) -> StoredValue<IdScope> {
+    let prefix = prefix.into();
+    let registry = use_context::<IdScopeContext>()
+        .map(|context| context.registry)
+        .unwrap_or_else(|| StoredValue::new(IdRegistry::new()));
+    if let Some(Err(collision)) =
+        registry.try_update_value(|registry| registry.register_prefix(prefix.clone()))
+    {
+        warn!("provide_id_scope: {collision:?}");
+    }
+    let scope = StoredValue::new(IdScope::root(prefix));
+    provide_context(IdScopeContext {
+        owner: Owner::current(),
+        scope,
+        registry,
+    });
+    scope
+}
+
+fn id_root_context() -> IdScopeContext {
+    let provide_root = || {
+        provide_id_scope(ID_DEFAULT_PREFIX);
+        use_context::<IdScopeContext>()
+    };
+    id_root_owner()
+        .and_then(|root| root.with(provide_root))
+        .unwrap_or_else(|| IdScopeContext {
+            owner: None,
+            scope: StoredValue::new(IdScope::root(ID_DEFAULT_PREFIX)),
+            registry: StoredValue::new(IdRegistry::new()),
+        })
+}
+
+fn use_id_context() -> IdScopeContext {
+    let owner = Owner::current();
+    let parent = use_context::<IdScopeContext>().unwrap_or_else(id_root_context);
+    if parent.owner == owner {
+        return parent;
+    }
+    let child = parent
+        .scope
+        .try_update_value(|scope| scope.child())
+        .unwrap_or_else(|| IdScope::root(ID_DEFAULT_PREFIX));
+    let context = IdScopeContext {
+        owner,
+        scope: StoredValue::new(child),
+        registry: parent.registry,
+    };
+    provide_context(context.clone());
+    context
+}
+
+pub fn use_id_scope() -> StoredValue<IdScope> {
+    use_id_context().scope
+}
+
+pub fn use_id() -> String {
+    let context = use_id_context();
+    let claimed = context.scope.try_update_value(|scope| {
+        context
+            .registry
+            .try_update_value(|registry| scope.next_id_in(registry))
+    });
+    match claimed.flatten() {
+        Some(Ok(id)) => {
+            let registry = context.registry;
+            let released = id.clone();
+            on_cleanup(move || {
+                registry.try_update_value(|registry| registry.release(&released));
+            });
+            id
+        }
+        Some(Err(IdCollision::DuplicateId(id))) => {
+            warn!("use_id: duplicate id {id}");
+            id
+        }
+        Some(Err(collision)) => {
+            warn!("use_id: {collision:?}");
+            String::new()
+        }
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{provide_id_scope, use_id};
+    use leptos::prelude::Owner;
+
+    #[test]
+    fn use_id_follows_owner_tree() {
+        let root = Owner::new();
+        root.with(|| {
+            provide_id_scope("app");
+            assert_eq!(use_id(), "app:1");
+            let first = Owner::current().expect("owner").child();
+            let second = Owner::current().expect("owner").child();
+            first.with(|| {
+                assert_eq!(use_id(), "app-1:1");
+                assert_eq!(use_id(), "app-1:2");
+            });
+            second.with(|| assert_eq!(use_id(), "app-2:1"));
+        });
+    }
+
+    #[test]
+    fn use_id_without_provider_roots_at_owner_tree() {
+        for _ in 0..2 {
+            let root = Owner::new();
+            root.with(|| {
+                let child = Owner::current().expect("owner").child();
+                child.with(|| assert_eq!(use_id(), "ui-1:1"));
+                assert_eq!(use_id(), "ui:1");
+            });
+        }
+    }
+}
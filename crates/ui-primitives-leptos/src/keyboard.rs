@@ -0,0 +1,14 @@
+use leptos::ev::KeyboardEvent;
+use ui_primitives_core::keyboard::{KeyInput, Modifiers};
+
+pub fn key_input_from_event(event: &KeyboardEvent) -> KeyInput {
+    KeyInput::parse(&event.key(), &event.code())
+        .with_modifiers(Modifiers {
+            shift: event.shift_key(),
+            ctrl: event.ctrl_key(),
+            alt: event.alt_key(),
+            meta: event.meta_key(),
+        })
+        .with_repeat(event.repeat())
+        .with_composing(event.is_composing())
+}
@@ -1,16 +1,25 @@
 #![forbid(unsafe_code)]
 
 mod attach;
+mod controllable;
+mod direction;
 mod dismissable;
 mod focus;
+mod hotkeys;
+mod ids;
+mod keyboard;
 mod modal;
+mod model;
 mod portal;
 mod presence;
 mod scroll_lock;
+mod sheet;
+mod timers;
 pub mod builders;
 
 pub use attach::{
     apply_attribute_delta,
+    primitive_attrs,
     PrimitiveAttribute,
     PrimitiveAttributeValue,
     PrimitiveElement,
@@ -19,6 +28,17 @@ pub use attach::{
     PrimitiveResult,
     use_primitive,
 };
+pub use controllable::{controllable_switch_warning, use_controllable_state};
+pub use direction::{
+    direction_current,
+    direction_from_document,
+    direction_provided,
+    provide_direction,
+    set_document_direction,
+    use_direction,
+    use_direction_model,
+    use_provided_direction,
+};
 pub use dismissable::{
     dismissable_is_escape,
     dismissable_is_outside,
@@ -30,6 +50,15 @@ pub use focus::{
     focus_scope_selector,
     FocusScope,
 };
+pub use hotkeys::{hotkeys_should_ignore, use_hotkey_scope, use_hotkeys};
+pub use ids::{
+    provide_id_scope,
+    use_id,
+    use_id_scope,
+    ID_DEFAULT_PREFIX,
+};
+pub use keyboard::key_input_from_event;
+pub use model::{model_send, model_send_key};
 pub use modal::{
     modal_hide_siblings,
     modal_restore,
@@ -51,3 +80,10 @@ pub use scroll_lock::{
     ScrollLockGuard,
     ScrollLockResult,
 };
+pub use sheet::{sheet_release_open, Sheet};
+pub use timers::{
+    timers_armed_delays,
+    timers_to_sync,
+    use_state_machine_timers,
+    TimeoutScheduler,
+};
@@ -0,0 +1,52 @@
+use leptos::ev::KeyboardEvent;
+use leptos::prelude::*;
+use ui_primitives_core::keyboard::KeyInput;
+use ui_primitives_core::model::{Model, Outcome};
+
+use crate::keyboard::key_input_from_event;
+
+pub fn model_send<M, S>(model: RwSignal<M, S>, event: M::Event) -> Outcome<M::Key>
+where
+    M: Model + 'static,
+    S: Storage<ArcRwSignal<M>>,
+{
+    model.try_update(|model| model.send(event)).unwrap_or_default()
+}
+
+pub fn model_send_key<M, S>(
+    model: RwSignal<M, S>,
+    event: &KeyboardEvent,
+    translate: impl FnOnce(KeyInput, u64) -> M::Event,
+) -> Outcome<M::Key>
+where
+    M: Model + 'static,
+    S: Storage<ArcRwSignal<M>>,
+{
+    let input = key_input_from_event(event);
+    let outcome = model_send(model, translate(input, event.time_stamp() as u64));
+    if outcome.prevents_default() {
+        event.prevent_default();
+    }
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::model_send;
+    use leptos::prelude::*;
+    use ui_primitives_core::collapsible::{CollapsibleEvent, CollapsibleModel};
+    use ui_primitives_core::model::Effect;
+
+    #[test]
+    fn model_send_updates_signal_and_returns_effects() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let model = RwSignal::new(CollapsibleModel::new(false));
+            assert_eq!(
+                model_send(model, CollapsibleEvent::Toggle).effects(),
+                [Effect::OpenChanged(true)]
+            );
+            assert!(model.get_untracked().open());
+        });
+    }
+}
@@ -32,6 +32,6 @@ pub fn Portal(
 mod tests {
     #[test]
     fn portal_availability_matches_target() {
-        assert!(!cfg!(target_arch = "wasm32"));
+        const { assert!(!cfg!(target_arch = "wasm32")) };
     }
 }
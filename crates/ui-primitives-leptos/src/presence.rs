@@ -43,16 +43,15 @@ pub fn Presence(
         }
     });
 
-    let on_exit_complete = on_exit_complete.clone();
     let end_handler = Arc::new(move || {
         let current_state = state.get_untracked();
         let next = presence_state_next(current_state, present.get_untracked(), true);
         if next != current_state {
             state.set(next);
-            if next == PresenceState::Unmounted {
-                if let Some(callback) = on_exit_complete.as_ref() {
-                    callback.run(());
-                }
+            if next == PresenceState::Unmounted
+                && let Some(callback) = on_exit_complete.as_ref()
+            {
+                callback.run(());
             }
         }
     });
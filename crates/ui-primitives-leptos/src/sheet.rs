@@ -0,0 +1,225 @@
+use crate::{
+    modal_hide_siblings,
+    primitive_attrs,
+    scroll_lock_acquire,
+    use_primitive,
+    DismissableLayer,
+    DismissableReason,
+    FocusScope,
+    ModalGuard,
+    ModalTarget,
+    ScrollLockGuard,
+};
+use leptos::ev::PointerEvent;
+use leptos::html;
+use leptos::prelude::*;
+use ui_primitives_core::aria::Attr;
+use ui_primitives_core::sheet::{self, SheetModel, SheetRelease};
+
+pub const SHEET_DRAG_THRESHOLD: f64 = 6.0;
+
+pub fn sheet_release_open(release: Option<SheetRelease>) -> Option<bool> {
+    match release? {
+        SheetRelease::Dismissed => Some(false),
+        SheetRelease::Snapped(_) => None,
+    }
+}
+
+pub fn sheet_drag_should_start(origin: f64, position: f64) -> bool {
+    (position - origin).abs() >= SHEET_DRAG_THRESHOLD
+}
+
+pub fn sheet_merge_style(model: &SheetModel, style: Option<&str>) -> String {
+    let vars = sheet::sheet_style_vars(model);
+    match style.map(|style| style.trim().trim_end_matches(';')) {
+        Some(style) if !style.is_empty() => format!("{style}; {vars}"),
+        _ => vars,
+    }
+}
+
+#[component]
+pub fn Sheet(
+    model: RwSignal<SheetModel>,
+    #[prop(optional, into)] labelled_by: Option<String>,
+    #[prop(optional, into)] described_by: Option<String>,
+    #[prop(optional, into)] style: Option<String>,
+    #[prop(optional)] on_open_change: Option<Callback<bool>>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let attrs = Signal::derive(move || {
+        model.with(|model| {
+            primitive_attrs(
+                sheet::sheet_content_attrs(model, labelled_by.as_deref(), described_by.as_deref())
+                    .with(Attr::Style(sheet_merge_style(model, style.as_deref()))),
+            )
+        })
+    });
+    let element = use_primitive::<html::Div>(attrs, vec![]);
+    let node_ref = element.node_ref();
+    let guards: StoredValue<Option<(Option<ModalGuard>, Option<ScrollLockGuard>)>> =
+        StoredValue::new(None);
+    let pending: StoredValue<Option<f64>> = StoredValue::new(None);
+
+    Effect::new(move || {
+        let open = model.with(|model| model.open());
+        let modal = model.with(|model| model.modal());
+        if !(open && modal) {
+            guards.set_value(None);
+            return;
+        }
+        if guards.with_value(|guards| guards.is_some()) {
+            return;
+        }
+        let Some(root) = sheet_modal_target(node_ref) else {
+            return;
+        };
+        let modal_guard = modal_hide_siblings(&root).ok();
+        guards.set_value(Some((modal_guard, scroll_lock_acquire().ok())));
+    });
+
+    on_cleanup(move || {
+        guards.set_value(None);
+    });
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        node_ref.on_load(move |_| {
+            if let Some(height) = web_sys::window()
+                .and_then(|window| window.inner_height().ok())
+                .and_then(|height| height.as_f64())
+            {
+                model.update(|model| model.set_viewport(height));
+            }
+        });
+    }
+
+    let close = move || {
+        let changed = model.try_update(|model| model.set_open(false)).unwrap_or(false);
+        if changed && let Some(callback) = on_open_change.as_ref() {
+            callback.run(false);
+        }
+    };
+
+    let on_pointerdown = move |event: PointerEvent| {
+        if event.button() != 0 || !model.with_untracked(|model| model.open()) {
+            return;
+        }
+        pending.set_value(Some(event.client_y() as f64));
+    };
+    let on_pointermove = move |event: PointerEvent| {
+        let position = event.client_y() as f64;
+        let time = event.time_stamp();
+        if !model.with_untracked(|model| model.dragging()) {
+            let Some(origin) = pending.get_value() else {
+                return;
+            };
+            if !sheet_drag_should_start(origin, position) {
+                return;
+            }
+            pending.set_value(None);
+            sheet_capture_pointer(&event);
+            model.update(|model| {
+                model.drag_start(origin, time);
+            });
+        }
+        model.update(|model| {
+            model.drag_move(position, time);
+        });
+    };
+    let on_pointerup = move |_event: PointerEvent| {
+        pending.set_value(None);
+        if !model.with_untracked(|model| model.dragging()) {
+            return;
+        }
+        let release = model.try_update(|model| model.drag_end()).flatten();
+        if sheet_release_open(release) == Some(false)
+            && let Some(callback) = on_open_change.as_ref()
+        {
+            callback.run(false);
+        }
+    };
+    let on_pointercancel = move |_event: PointerEvent| {
+        pending.set_value(None);
+        model.update(|model| model.drag_cancel());
+    };
+
+    let on_dismiss = Callback::new(move |_reason: DismissableReason| {
+        if model.with_untracked(|model| model.dismissible()) {
+            close();
+        }
+    });
+
+    let children = StoredValue::new(children);
+
+    view! {
+        <div
+            node_ref=node_ref
+            on:pointerdown=on_pointerdown
+            on:pointermove=on_pointermove
+            on:pointerup=on_pointerup
+            on:pointercancel=on_pointercancel
+        >
+            <DismissableLayer on_dismiss=on_dismiss>
+                <FocusScope trapped=true auto_focus=true return_focus=true>
+                    {children.with_value(|children| children())}
+                </FocusScope>
+            </DismissableLayer>
+        </div>
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn sheet_modal_target(node_ref: NodeRef<html::Div>) -> Option<ModalTarget> {
+    node_ref.get().map(Into::into)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn sheet_modal_target(node_ref: NodeRef<html::Div>) -> Option<ModalTarget> {
+    let _ = node_ref;
+    Some(())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn sheet_capture_pointer(event: &PointerEvent) {
+    use wasm_bindgen::JsCast;
+
+    if let Some(target) = event
+        .current_target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+    {
+        let _ = target.set_pointer_capture(event.pointer_id());
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn sheet_capture_pointer(event: &PointerEvent) {
+    let _ = event;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sheet_drag_should_start, sheet_merge_style, sheet_release_open};
+    use ui_primitives_core::sheet::{SheetModel, SheetRelease};
+
+    #[test]
+    fn sheet_release_reports_dismiss() {
+        assert_eq!(sheet_release_open(Some(SheetRelease::Dismissed)), Some(false));
+        assert_eq!(sheet_release_open(Some(SheetRelease::Snapped(1))), None);
+        assert_eq!(sheet_release_open(None), None);
+    }
+
+    #[test]
+    fn sheet_drag_waits_for_threshold() {
+        assert!(!sheet_drag_should_start(100.0, 103.0));
+        assert!(sheet_drag_should_start(100.0, 106.0));
+        assert!(sheet_drag_should_start(100.0, 90.0));
+    }
+
+    #[test]
+    fn sheet_style_keeps_user_declarations() {
+        let model = SheetModel::new(true);
+        let style = sheet_merge_style(&model, Some("background: red;"));
+        assert!(style.starts_with("background: red; --sheet-drag-offset"));
+        assert!(!sheet_merge_style(&model, Some("  ")).starts_with(';'));
+    }
+}
@@ -0,0 +1,262 @@
+use leptos::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use ui_primitives_core::clock::{Clock, Scheduler, TimerId, TimerTask};
+use ui_primitives_core::state_machine::StateMachine;
+
+#[cfg(target_arch = "wasm32")]
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::OnceLock;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+#[cfg(target_arch = "wasm32")]
+type TimeoutEntry = (TimerId, TimeoutHandle);
+
+#[cfg(not(target_arch = "wasm32"))]
+type TimeoutEntry = (TimerId, TimerTask);
+
+#[derive(Default)]
+struct TimeoutState {
+    next_id: TimerId,
+    pending: Vec<TimeoutEntry>,
+}
+
+#[derive(Clone, Default)]
+pub struct TimeoutScheduler {
+    state: Rc<RefCell<TimeoutState>>,
+}
+
+impl TimeoutScheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn pending(&self) -> usize {
+        self.state.borrow().pending.len()
+    }
+
+    fn next_id(&self) -> TimerId {
+        let mut state = self.state.borrow_mut();
+        let id = state.next_id;
+        state.next_id += 1;
+        id
+    }
+}
+
+impl std::fmt::Debug for TimeoutScheduler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TimeoutScheduler")
+            .field("pending", &self.pending())
+            .finish()
+    }
+}
+
+impl Clock for TimeoutScheduler {
+    fn now(&self) -> u64 {
+        timeout_now()
+    }
+}
+
+impl Scheduler for TimeoutScheduler {
+    #[cfg(target_arch = "wasm32")]
+    fn schedule(&self, delay_ms: u64, task: TimerTask) -> TimerId {
+        let id = self.next_id();
+        let state = Rc::clone(&self.state);
+        let handle = set_timeout_with_handle(
+            move || {
+                state.borrow_mut().pending.retain(|(pending, _)| *pending != id);
+                task();
+            },
+            Duration::from_millis(delay_ms),
+        );
+        if let Ok(handle) = handle {
+            self.state.borrow_mut().pending.push((id, handle));
+        }
+        id
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn schedule(&self, delay_ms: u64, task: TimerTask) -> TimerId {
+        let _ = delay_ms;
+        let id = self.next_id();
+        self.state.borrow_mut().pending.push((id, task));
+        id
+    }
+
+    fn cancel(&self, id: TimerId) -> bool {
+        let mut state = self.state.borrow_mut();
+        let Some(index) = state.pending.iter().position(|(pending, _)| *pending == id) else {
+            return false;
+        };
+        let (_, entry) = state.pending.remove(index);
+        timeout_clear(entry);
+        true
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn timeout_clear(handle: TimeoutHandle) {
+    handle.clear();
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn timeout_clear(task: TimerTask) {
+    drop(task);
+}
+
+#[cfg(target_arch = "wasm32")]
+fn timeout_now() -> u64 {
+    web_sys::window()
+        .and_then(|window| window.performance())
+        .map(|performance| performance.now() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn timeout_now() -> u64 {
+    static START: OnceLock<Instant> = OnceLock::new();
+    START.get_or_init(Instant::now).elapsed().as_millis() as u64
+}
+
+pub fn timers_to_sync(
+    armed: &[(TimerId, u64)],
+    scheduled: &[(TimerId, TimerId)],
+) -> (Vec<TimerId>, Vec<(TimerId, u64)>) {
+    let cancel = scheduled
+        .iter()
+        .filter(|(token, _)| !armed.iter().any(|(id, _)| id == token))
+        .map(|(_, handle)| *handle)
+        .collect();
+    let schedule = armed
+        .iter()
+        .filter(|(id, _)| !scheduled.iter().any(|(token, _)| token == id))
+        .copied()
+        .collect();
+    (cancel, schedule)
+}
+
+pub fn timers_armed_delays<S, E, C>(machine: &StateMachine<S, E, C>) -> Vec<(TimerId, u64)> {
+    let now = machine.now();
+    machine
+        .armed_timers()
+        .iter()
+        .map(|timer| (timer.id, timer.deadline.saturating_sub(now)))
+        .collect()
+}
+
+pub fn use_state_machine_timers<S, E, C>(
+    machine: RwSignal<StateMachine<S, E, C>, LocalStorage>,
+    scheduler: Rc<dyn Scheduler>,
+) where
+    S: PartialEq + Clone + 'static,
+    E: PartialEq + 'static,
+    C: 'static,
+{
+    let scheduler = StoredValue::new_local(scheduler);
+    let scheduled: StoredValue<Vec<(TimerId, TimerId)>, LocalStorage> =
+        StoredValue::new_local(Vec::new());
+
+    Effect::new(move || {
+        let armed = machine.with(timers_armed_delays);
+        let scheduler = scheduler.get_value();
+        let (cancel, schedule) = scheduled.with_value(|scheduled| timers_to_sync(&armed, scheduled));
+        scheduled.update_value(|scheduled| {
+            scheduled.retain(|(_, handle)| !cancel.contains(handle));
+        });
+        for handle in cancel {
+            scheduler.cancel(handle);
+        }
+
+        for (token, delay) in schedule {
+            let handle = scheduler.schedule(
+                delay,
+                Box::new(move || {
+                    scheduled.update_value(|scheduled| {
+                        scheduled.retain(|(pending, _)| *pending != token);
+                    });
+                    machine.update(|machine| {
+                        machine.fire_timer(token);
+                    });
+                }),
+            );
+            scheduled.update_value(|scheduled| scheduled.push((token, handle)));
+        }
+    });
+
+    on_cleanup(move || {
+        let handles: Vec<TimerId> = scheduled
+            .try_with_value(|scheduled| scheduled.iter().map(|(_, handle)| *handle).collect())
+            .unwrap_or_default();
+        if let Some(scheduler) = scheduler.try_get_value() {
+            for handle in handles {
+                scheduler.cancel(handle);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{timers_armed_delays, timers_to_sync, TimeoutScheduler};
+    use std::cell::RefCell;
+    use ui_primitives_core::clock::{Clock, Scheduler, TimerId, TimerTask};
+    use ui_primitives_core::state_machine::StateMachine;
+
+    #[derive(Default)]
+    struct RecordingScheduler {
+        delays: RefCell<Vec<u64>>,
+    }
+
+    impl Clock for RecordingScheduler {
+        fn now(&self) -> u64 {
+            5_000_000
+        }
+    }
+
+    impl Scheduler for RecordingScheduler {
+        fn schedule(&self, delay_ms: u64, _task: TimerTask) -> TimerId {
+            let mut delays = self.delays.borrow_mut();
+            delays.push(delay_ms);
+            delays.len() as TimerId
+        }
+
+        fn cancel(&self, _id: TimerId) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn sync_cancels_stale_and_schedules_new_timers() {
+        let armed = [(3, 700), (4, 900)];
+        let scheduled = [(2, 10), (3, 11)];
+        let (cancel, schedule) = timers_to_sync(&armed, &scheduled);
+        assert_eq!(cancel, vec![10]);
+        assert_eq!(schedule, vec![(4, 900)]);
+    }
+
+    #[test]
+    fn timeout_scheduler_tracks_pending_tasks() {
+        let scheduler = TimeoutScheduler::new();
+        let id = scheduler.schedule(100, Box::new(|| {}));
+        assert_eq!(scheduler.pending(), 1);
+        assert!(scheduler.cancel(id));
+        assert!(!scheduler.cancel(id));
+        assert_eq!(scheduler.pending(), 0);
+    }
+
+    #[test]
+    fn delays_use_machine_time_base_with_mismatched_scheduler() {
+        let mut machine: StateMachine<u8, u8> = StateMachine::new(0);
+        machine.after(0, 300, 1);
+        machine.advance_to(120);
+        let scheduler = RecordingScheduler::default();
+        let (_, schedule) = timers_to_sync(&timers_armed_delays(&machine), &[]);
+        for (_, delay) in schedule {
+            scheduler.schedule(delay, Box::new(|| {}));
+        }
+        assert_eq!(scheduler.delays.into_inner(), vec![180]);
+    }
+}